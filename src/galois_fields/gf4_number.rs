@@ -1,3 +1,5 @@
+use std::fmt;
+use std::str::FromStr;
 use rand::Rng;
 use rand::seq::SliceRandom;
 use crate::galois_fields::GaloisField;
@@ -53,6 +55,51 @@ impl GF4 {
     }
 }
 
+// Written as 0, 1, a, a+1, or with the alternate flag ({:#}) in the
+// multiplicative notation 0, 1, ω, ω².
+impl fmt::Display for GF4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match (self, f.alternate()) {
+            (GF4::Zero, _) => "0",
+            (GF4::One, _) => "1",
+            (GF4::Alpha, false) => "a",
+            (GF4::AlphaPlusOne, false) => "a+1",
+            (GF4::Alpha, true) => "ω",
+            (GF4::AlphaPlusOne, true) => "ω²",
+        };
+        f.write_str(s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseGF4Error {
+    input: String,
+}
+
+impl fmt::Display for ParseGF4Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' is not an element of GF(4)", self.input)
+    }
+}
+
+impl std::error::Error for ParseGF4Error {}
+
+// Accepts both notations of Display, with w for ω and ^2 for ², ignoring whitespace.
+impl FromStr for GF4 {
+    type Err = ParseGF4Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let compact: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        match compact.as_str() {
+            "0" => Ok(GF4::Zero),
+            "1" => Ok(GF4::One),
+            "a" | "ω" | "w" => Ok(GF4::Alpha),
+            "a+1" | "1+a" | "ω²" | "ω^2" | "w^2" => Ok(GF4::AlphaPlusOne),
+            _ => Err(ParseGF4Error { input: s.to_string() }),
+        }
+    }
+}
+
 impl GaloisField for GF4 {
     // type Output = GF4;
     
@@ -95,11 +142,25 @@ impl GaloisField for GF4 {
             Some(DIVISION[(self.to_number()) as usize][(other.to_number()) as usize - 1].clone())
         }
     }
+
+    fn order() -> usize {
+        4
+    }
+
+    fn characteristic() -> usize {
+        2
+    }
+
+    fn elements() -> Vec<GF4> {
+        vec![GF4::Zero, GF4::One, GF4::Alpha, GF4::AlphaPlusOne]
+    }
 }
 
 #[cfg(test)]
 mod gf4_tests {
-    use std::collections::HashMap;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+    use crate::random::statistics::{chi_square_critical_value, chi_square_uniform};
     use super::*;
 
     #[test]
@@ -145,51 +206,21 @@ mod gf4_tests {
         assert!(!GF4::AlphaPlusOne.is_one());
     }
 
-    /*
-    #[test]
-    fn test_gf4_make_random_context() {
-    }
-
     #[test]
     fn test_gf4_generate_random_uniform() {
-        let mut ctx = FiniteFieldGenerator::new(0u8, 3u8);
-        let generated = GF4::generate_random_uniform(&mut ctx);
-        assert!(vec![GF4::Zero, GF4::One, GF4::Alpha, GF4::AlphaPlusOne].contains(&generated));
-
-        // chi squared test of uniformity
-        // there are 4 options => 3 degrees of freedom
-        // probab alpha = 0.05
-        // the value of chi squared statistic can therefore be precomputed
-        // in this case, the value is 7.815
-        // this value was sourced from https://medcalc.org/manual/chi-square-table.php
-        // given we are testing uniformity and there are 4 options
-        // we expect probability of generating a specific option to be 1/4
-        // that means out of N samples, the expected count of each option is N*0.25
-
-        let num_samples = 10000;
-        let expected_per_option = num_samples as f64 / 4.0;
-        let mut sample_counts = HashMap::<u8, usize>::new();
-
-        sample_counts.insert(GF4::Zero.to_number(), 0);
-        sample_counts.insert(GF4::One.to_number(), 0);
-        sample_counts.insert(GF4::Alpha.to_number(), 0);
-        sample_counts.insert(GF4::AlphaPlusOne.to_number(), 0);
-
-        for _ in 0..num_samples {
-            let generated = GF4::generate_random_uniform(&mut ctx).to_number();
-            sample_counts.insert(generated, sample_counts.get(&generated).unwrap() + 1);
-        }
-
-        let mut stat = 0.0;
-        for (key, value) in sample_counts {
-            let diff_squared = (value as f64 - expected_per_option).powi(2);
-            stat += diff_squared;
+        // fixed seed, so the outcome is the same on every run
+        let mut rng = ChaCha20Rng::from_seed([0x04; 32]);
+        let generated = GF4::generate_random(&mut rng);
+        assert!([GF4::Zero, GF4::One, GF4::Alpha, GF4::AlphaPlusOne].contains(&generated));
+
+        // chi-square test of uniformity at statistics::SIGNIFICANCE_LEVEL;
+        // 4 options => 3 degrees of freedom
+        let mut counts = [0; 4];
+        for _ in 0..10000 {
+            counts[GF4::generate_random(&mut rng).to_number() as usize] += 1;
         }
-        stat /= expected_per_option;
-
-        assert!(stat < 7.815);
+        assert!(chi_square_uniform(&counts) < chi_square_critical_value(3), "{:?}", counts);
     }
-    */
 
     #[test]
     fn test_gf4_add() {
@@ -298,4 +329,44 @@ mod gf4_tests {
         assert_eq!(GF4::AlphaPlusOne.div(&GF4::Alpha).unwrap(), GF4::Alpha);
         assert_eq!(GF4::AlphaPlusOne.div(&GF4::AlphaPlusOne).unwrap(), GF4::One);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_gf4_elements() {
+        assert_eq!(GF4::order(), 4);
+        assert_eq!(GF4::characteristic(), 2);
+        assert_eq!(GF4::elements(), vec![GF4::Zero, GF4::One, GF4::Alpha, GF4::AlphaPlusOne]);
+    }
+
+    #[test]
+    fn test_gf4_pow() {
+        assert_eq!(GF4::Alpha.pow(0), GF4::One);
+        assert_eq!(GF4::Alpha.pow(1), GF4::Alpha);
+        assert_eq!(GF4::Alpha.pow(2), GF4::AlphaPlusOne);
+        assert_eq!(GF4::Alpha.pow(3), GF4::One);
+        assert_eq!(GF4::AlphaPlusOne.pow(5), GF4::Alpha);
+        assert_eq!(GF4::Zero.pow(0), GF4::One);
+        assert_eq!(GF4::Zero.pow(7), GF4::Zero);
+    }
+
+    #[test]
+    fn test_gf4_display() {
+        assert_eq!(format!("{}", GF4::Zero), "0");
+        assert_eq!(format!("{}", GF4::One), "1");
+        assert_eq!(format!("{}", GF4::Alpha), "a");
+        assert_eq!(format!("{}", GF4::AlphaPlusOne), "a+1");
+        assert_eq!(format!("{:#}", GF4::Alpha), "ω");
+        assert_eq!(format!("{:#}", GF4::AlphaPlusOne), "ω²");
+    }
+
+    #[test]
+    fn test_gf4_from_str() {
+        for x in GF4::elements() {
+            assert_eq!(format!("{}", x).parse::<GF4>(), Ok(x.clone()));
+            assert_eq!(format!("{:#}", x).parse::<GF4>(), Ok(x));
+        }
+        assert_eq!(" a + 1 ".parse::<GF4>(), Ok(GF4::AlphaPlusOne));
+        assert_eq!("w^2".parse::<GF4>(), Ok(GF4::AlphaPlusOne));
+        assert!("2".parse::<GF4>().is_err());
+        assert!("".parse::<GF4>().is_err());
+    }
+}
@@ -1,5 +1,4 @@
 use std::fmt::Debug;
-use rand::distributions::{Distribution, Uniform};
 use rand::Rng;
 
 pub mod gf4_number;
@@ -15,4 +14,22 @@ pub trait GaloisField: Clone + Eq + PartialEq<Self> + Debug {
     fn sub(&self, other: &Self) -> Self;
     fn mul(&self, other: &Self) -> Self;
     fn div(&self, other: &Self) -> Option<Self>;
+    fn order() -> usize;
+    fn characteristic() -> usize;
+    // All field elements, starting with zero and one.
+    fn elements() -> Vec<Self>;
+
+    fn pow(&self, exponent: usize) -> Self {
+        let mut result = Self::generate_one();
+        let mut base = self.clone();
+        let mut e = exponent;
+        while e > 0 {
+            if e & 1 == 1 {
+                result = result.mul(&base);
+            }
+            base = base.mul(&base);
+            e >>= 1;
+        }
+        result
+    }
 }
\ No newline at end of file
@@ -0,0 +1,6 @@
+use crate::galois_fields::GaloisField;
+
+pub mod galois_fields;
+pub mod matrices;
+pub mod polynomials;
+pub mod random;
@@ -1,14 +1,7 @@
-use crate::galois_fields::GaloisField;
-
-mod galois_fields;
-mod polynomials;
-mod random;
+use mdpc_gf_4::galois_fields::gf4_number::GF4;
+use mdpc_gf_4::polynomials::polynomial::Polynomial;
 
 fn main() {
-    use galois_fields::gf4_number::GF4;
-    use polynomials::polynomial::Polynomial;
-
-
     let p1 = Polynomial::new_from_coefficients(vec![
         GF4::Zero, GF4::One, GF4::Alpha, GF4::Alpha, GF4::AlphaPlusOne
     ]);
@@ -17,5 +10,5 @@ fn main() {
     ]);
 
     let p3 = p1.mul(&p2);
-    println!("{:?}", p3);
+    println!("{}", p3);
 }
@@ -0,0 +1,132 @@
+use crate::galois_fields::GaloisField;
+use crate::matrices::dense_matrix::DenseMatrix;
+use crate::polynomials::polynomial::Polynomial;
+
+// Row i of the circulant of a is x^i * a mod x^r - 1, so entry (i, j) is
+// a_{(j - i) mod r}. With this convention a row vector e times C(a) is the
+// coefficient vector of e * a mod x^r - 1, and C(a) * C(b) = C(a * b).
+pub fn circulant_matrix<T: GaloisField>(poly: &Polynomial<T>, r: usize) -> DenseMatrix<T> {
+    let padded = poly.to_padded(r);
+    let mut m = DenseMatrix::new(r, r);
+    for i in 0..r {
+        for j in 0..r {
+            m.set(i, j, padded[(j + r - i) % r].clone());
+        }
+    }
+    m
+}
+
+// The polynomial whose circulant is m, read from the first row; None if m is
+// not square or not circulant.
+pub fn polynomial_from_circulant<T: GaloisField>(m: &DenseMatrix<T>) -> Option<Polynomial<T>> {
+    let r = m.rows();
+    if m.cols() != r {
+        return None;
+    }
+    let first_row = m.get_row(0).map_or(Vec::new(), |row| row.to_vec());
+    for i in 1..r {
+        for j in 0..r {
+            if m.get(i, j)? != first_row[(j + r - i) % r] {
+                return None;
+            }
+        }
+    }
+    Some(Polynomial::new_from_coefficients(first_row))
+}
+
+// The r x (n0 * r) block matrix [C(p_0) | C(p_1) | ... | C(p_{n0-1})].
+pub fn quasi_cyclic_matrix<T: GaloisField>(polys: &[Polynomial<T>], r: usize) -> DenseMatrix<T> {
+    let mut m = DenseMatrix::new(r, polys.len() * r);
+    for (block, poly) in polys.iter().enumerate() {
+        let c = circulant_matrix(poly, r);
+        for i in 0..r {
+            for j in 0..r {
+                m.set(i, block * r + j, c.get(i, j).unwrap());
+            }
+        }
+    }
+    m
+}
+
+impl<T> Polynomial<T>
+where T: GaloisField {
+
+    pub fn to_circulant(&self, r: usize) -> DenseMatrix<T> {
+        circulant_matrix(self, r)
+    }
+
+    pub fn from_circulant(m: &DenseMatrix<T>) -> Option<Polynomial<T>> {
+        polynomial_from_circulant(m)
+    }
+}
+
+#[cfg(test)]
+mod circulant_tests {
+    use crate::galois_fields::gf4_number::GF4;
+    use super::*;
+
+    #[test]
+    fn test_circulant_matrix() {
+        let p = Polynomial::new_from_coefficients(vec![GF4::One, GF4::Alpha, GF4::Zero]);
+        let expected = DenseMatrix::new_from_rows(vec![
+            vec![GF4::One, GF4::Alpha, GF4::Zero],
+            vec![GF4::Zero, GF4::One, GF4::Alpha],
+            vec![GF4::Alpha, GF4::Zero, GF4::One],
+        ]).unwrap();
+        assert_eq!(p.to_circulant(3), expected);
+        assert_eq!(Polynomial::from_circulant(&expected), Some(p));
+        for i in 0..3 {
+            let row = Polynomial::new_from_coefficients(expected.get_row(i).unwrap().to_vec());
+            assert_eq!(row, Polynomial::new_from_coefficients(vec![GF4::One, GF4::Alpha]).rotate(i, 3));
+        }
+    }
+
+    #[test]
+    fn test_polynomial_from_circulant() {
+        let not_circulant = DenseMatrix::new_from_rows(vec![
+            vec![GF4::One, GF4::Alpha],
+            vec![GF4::Zero, GF4::One],
+        ]).unwrap();
+        assert!(polynomial_from_circulant(&not_circulant).is_none());
+        assert!(polynomial_from_circulant(&DenseMatrix::<GF4>::new(2, 3)).is_none());
+        assert_eq!(polynomial_from_circulant(&DenseMatrix::<GF4>::identity(4)), Some(Polynomial::new_from_coefficients(vec![GF4::One])));
+    }
+
+    #[test]
+    fn test_circulant_matches_polynomial_arithmetic() {
+        let r = 7;
+        let a: Polynomial<GF4> = "a*x^6 + x^3 + (a+1)*x + 1".parse().unwrap();
+        let b: Polynomial<GF4> = "x^5 + a*x^4 + a".parse().unwrap();
+        let ab = a.mul(&b).reduce_cyclic(r);
+
+        assert_eq!(circulant_matrix(&a, r).mul(&circulant_matrix(&b, r)), Some(circulant_matrix(&ab, r)));
+        assert_eq!(circulant_matrix(&a, r).vector_mul(&b.to_padded(r)), Some(ab.to_padded(r)));
+        assert_eq!(circulant_matrix(&a, r).add(&circulant_matrix(&b, r)), Some(circulant_matrix(&a.add(&b), r)));
+        // the transpose of a circulant is the circulant of the transposed polynomial
+        assert_eq!(circulant_matrix(&a, r).transpose(), circulant_matrix(&a.transpose(r), r));
+    }
+
+    #[test]
+    fn test_quasi_cyclic_matrix() {
+        let r = 5;
+        let h0: Polynomial<GF4> = "x^3 + a*x + 1".parse().unwrap();
+        let h1: Polynomial<GF4> = "(a+1)*x^4 + x^2".parse().unwrap();
+        let h = quasi_cyclic_matrix(&[h0.clone(), h1.clone()], r);
+        assert_eq!(h.rows(), r);
+        assert_eq!(h.cols(), 2 * r);
+        for i in 0..r {
+            let row = h.get_row(i).unwrap();
+            assert_eq!(row[..r].to_vec(), h0.rotate(i, r).to_padded(r));
+            assert_eq!(row[r..].to_vec(), h1.rotate(i, r).to_padded(r));
+        }
+
+        // syndrome of (e0, e1) in matrix form equals e0 * h0^T + e1 * h1^T in polynomial form
+        let e0: Polynomial<GF4> = "x^2 + a".parse().unwrap();
+        let e1: Polynomial<GF4> = "a*x^4".parse().unwrap();
+        let mut e = e0.to_padded(r);
+        e.extend(e1.to_padded(r));
+        let s = DenseMatrix::new_from_rows(vec![e]).unwrap().mul(&h.transpose()).unwrap();
+        let expected = e0.mul(&h0.transpose(r)).add(&e1.mul(&h1.transpose(r))).reduce_cyclic(r);
+        assert_eq!(s.get_row(0).unwrap().to_vec(), expected.to_padded(r));
+    }
+}
@@ -0,0 +1,181 @@
+use crate::galois_fields::GaloisField;
+
+// Row-major matrix over a Galois field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenseMatrix<T: GaloisField> {
+    rows: usize,
+    cols: usize,
+    entries: Vec<T>,
+}
+
+impl<T> DenseMatrix<T>
+where T: GaloisField {
+
+    pub fn new(rows: usize, cols: usize) -> DenseMatrix<T> {
+        DenseMatrix {
+            rows,
+            cols,
+            entries: vec![T::generate_zero(); rows * cols],
+        }
+    }
+
+    pub fn identity(n: usize) -> DenseMatrix<T> {
+        let mut m = DenseMatrix::new(n, n);
+        for i in 0..n {
+            m.entries[i * n + i] = T::generate_one();
+        }
+        m
+    }
+
+    // None if the rows differ in length.
+    pub fn new_from_rows(rows: Vec<Vec<T>>) -> Option<DenseMatrix<T>> {
+        let cols = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != cols) {
+            return None;
+        }
+        Some(DenseMatrix {
+            rows: rows.len(),
+            cols,
+            entries: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<T> {
+        if i < self.rows && j < self.cols {
+            Some(self.entries[i * self.cols + j].clone())
+        } else {
+            None
+        }
+    }
+
+    // Returns false if (i, j) is out of bounds.
+    pub fn set(&mut self, i: usize, j: usize, value: T) -> bool {
+        if i < self.rows && j < self.cols {
+            self.entries[i * self.cols + j] = value;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn get_row(&self, i: usize) -> Option<&[T]> {
+        if i < self.rows {
+            Some(&self.entries[i * self.cols..(i + 1) * self.cols])
+        } else {
+            None
+        }
+    }
+
+    pub fn transpose(&self) -> DenseMatrix<T> {
+        let mut t = DenseMatrix::new(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                t.entries[j * self.rows + i] = self.entries[i * self.cols + j].clone();
+            }
+        }
+        t
+    }
+
+    pub fn add(&self, other: &DenseMatrix<T>) -> Option<DenseMatrix<T>> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return None;
+        }
+        Some(DenseMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            entries: self.entries.iter().zip(other.entries.iter()).map(|(a, b)| a.add(b)).collect(),
+        })
+    }
+
+    // None if the inner dimensions differ.
+    pub fn mul(&self, other: &DenseMatrix<T>) -> Option<DenseMatrix<T>> {
+        if self.cols != other.rows {
+            return None;
+        }
+        let mut product = DenseMatrix::<T>::new(self.rows, other.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = &self.entries[i * self.cols + k];
+                if a.is_zero() {
+                    continue;
+                }
+                for j in 0..other.cols {
+                    let index = i * other.cols + j;
+                    product.entries[index] = product.entries[index].add(&a.mul(&other.entries[k * other.cols + j]));
+                }
+            }
+        }
+        Some(product)
+    }
+
+    // Row vector times matrix; None if the length does not match the row count.
+    pub fn vector_mul(&self, v: &[T]) -> Option<Vec<T>> {
+        if v.len() != self.rows {
+            return None;
+        }
+        let row = DenseMatrix::new_from_rows(vec![v.to_vec()])?;
+        Some(row.mul(self)?.entries)
+    }
+}
+
+#[cfg(test)]
+mod dense_matrix_tests {
+    use crate::galois_fields::gf4_number::GF4;
+    use super::*;
+
+    #[test]
+    fn test_dense_matrix_new_from_rows() {
+        let m = DenseMatrix::new_from_rows(vec![
+            vec![GF4::One, GF4::Alpha, GF4::Zero],
+            vec![GF4::Zero, GF4::AlphaPlusOne, GF4::One],
+        ]).unwrap();
+        assert_eq!(m.rows(), 2);
+        assert_eq!(m.cols(), 3);
+        assert_eq!(m.get(0, 1), Some(GF4::Alpha));
+        assert_eq!(m.get(1, 1), Some(GF4::AlphaPlusOne));
+        assert!(m.get(2, 0).is_none());
+        assert_eq!(m.get_row(1), Some(&[GF4::Zero, GF4::AlphaPlusOne, GF4::One][..]));
+
+        assert!(DenseMatrix::new_from_rows(vec![vec![GF4::One], vec![]]).is_none());
+    }
+
+    #[test]
+    fn test_dense_matrix_set_transpose() {
+        let mut m: DenseMatrix<GF4> = DenseMatrix::new(2, 3);
+        assert!(m.set(0, 2, GF4::Alpha));
+        assert!(!m.set(2, 0, GF4::Alpha));
+        let t = m.transpose();
+        assert_eq!(t.rows(), 3);
+        assert_eq!(t.get(2, 0), Some(GF4::Alpha));
+        assert_eq!(t.transpose(), m);
+    }
+
+    #[test]
+    fn test_dense_matrix_mul() {
+        let a = DenseMatrix::new_from_rows(vec![
+            vec![GF4::One, GF4::Alpha],
+            vec![GF4::AlphaPlusOne, GF4::Zero],
+        ]).unwrap();
+        let b = DenseMatrix::new_from_rows(vec![
+            vec![GF4::Alpha, GF4::One, GF4::Zero],
+            vec![GF4::One, GF4::Zero, GF4::AlphaPlusOne],
+        ]).unwrap();
+        let expected = DenseMatrix::new_from_rows(vec![
+            vec![GF4::Zero, GF4::One, GF4::One],
+            vec![GF4::One, GF4::AlphaPlusOne, GF4::Zero],
+        ]).unwrap();
+        assert_eq!(a.mul(&b), Some(expected));
+        assert_eq!(a.mul(&DenseMatrix::identity(2)), Some(a.clone()));
+        assert!(b.mul(&a).is_none());
+        assert_eq!(a.vector_mul(&[GF4::One, GF4::One]), Some(vec![GF4::Alpha, GF4::Alpha]));
+        assert_eq!(a.add(&a), Some(DenseMatrix::new(2, 2)));
+    }
+}
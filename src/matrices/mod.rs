@@ -0,0 +1,2 @@
+pub mod dense_matrix;
+pub mod circulant;
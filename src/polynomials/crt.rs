@@ -0,0 +1,150 @@
+use crate::galois_fields::GaloisField;
+use crate::polynomials::polynomial::Polynomial;
+
+// Precomputed data for F[x]/(m_0 * ... * m_{k-1}) = F[x]/(m_0) x ... x F[x]/(m_{k-1})
+// with pairwise coprime moduli. Reconstruction uses the idempotents
+// e_i = 1 mod m_i, e_i = 0 mod m_j (j != i), obtained from the extended
+// Euclidean algorithm as e_i = (M / m_i) * ((M / m_i)^-1 mod m_i).
+#[derive(Debug, Clone)]
+pub struct CrtBasis<T: GaloisField> {
+    moduli: Vec<Polynomial<T>>,
+    product: Polynomial<T>,
+    idempotents: Vec<Polynomial<T>>,
+}
+
+impl<T> CrtBasis<T>
+where T: GaloisField {
+
+    // None if a modulus is zero or two moduli share a common factor.
+    pub fn new(moduli: Vec<Polynomial<T>>) -> Option<CrtBasis<T>> {
+        if moduli.iter().any(|m| m.is_zero()) {
+            return None;
+        }
+        let product = moduli.iter().fold(
+            Polynomial::new_from_coefficients(vec![T::generate_one()]),
+            |acc, m| acc.mul(m),
+        );
+        let mut idempotents = Vec::with_capacity(moduli.len());
+        for m in moduli.iter() {
+            let (cofactor, _) = product.div_mod(m)?;
+            let inv = cofactor.invert(m)?;
+            let (_, e) = cofactor.mul(&inv).div_mod(&product)?;
+            idempotents.push(e);
+        }
+        Some(CrtBasis {
+            moduli,
+            product,
+            idempotents,
+        })
+    }
+
+    pub fn moduli(&self) -> &[Polynomial<T>] {
+        &self.moduli
+    }
+
+    // The product of all moduli.
+    pub fn modulus(&self) -> &Polynomial<T> {
+        &self.product
+    }
+
+    pub fn to_residues(&self, poly: &Polynomial<T>) -> Vec<Polynomial<T>> {
+        self.moduli.iter().map(|m| poly.div_mod(m).unwrap().1).collect()
+    }
+
+    // The unique polynomial of degree < deg M with the given residues;
+    // None if the number of residues does not match the number of moduli.
+    pub fn from_residues(&self, residues: &[Polynomial<T>]) -> Option<Polynomial<T>> {
+        if residues.len() != self.moduli.len() {
+            return None;
+        }
+        let mut result = Polynomial::new();
+        for (residue, e) in residues.iter().zip(self.idempotents.iter()) {
+            result = result.add(&residue.mul(e));
+        }
+        Some(result.div_mod(&self.product)?.1)
+    }
+
+    // Component-wise product of two residue vectors.
+    pub fn mul_residues(&self, a: &[Polynomial<T>], b: &[Polynomial<T>]) -> Option<Vec<Polynomial<T>>> {
+        if a.len() != self.moduli.len() || b.len() != self.moduli.len() {
+            return None;
+        }
+        Some(
+            a.iter()
+                .zip(b.iter())
+                .zip(self.moduli.iter())
+                .map(|((x, y), m)| x.mul(y).div_mod(m).unwrap().1)
+                .collect()
+        )
+    }
+}
+
+#[cfg(test)]
+mod crt_tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use crate::galois_fields::gf4_number::GF4;
+    use crate::polynomials::factorization::factor;
+    use super::*;
+
+    fn basis_for_x_pow_minus_one(r: usize) -> CrtBasis<GF4> {
+        let mut coefficients = vec![GF4::Zero; r + 1];
+        coefficients[0] = GF4::One;
+        coefficients[r] = GF4::One;
+        let mut rng = StdRng::seed_from_u64(5);
+        let factors = factor(&Polynomial::new_from_coefficients(coefficients), &mut rng);
+        CrtBasis::new(factors.into_iter().map(|(f, _)| f).collect()).unwrap()
+    }
+
+    #[test]
+    fn test_crt_new() {
+        let basis = basis_for_x_pow_minus_one(5);
+        assert_eq!(basis.moduli().len(), 3);
+        assert_eq!(basis.modulus(), &"x^5 + 1".parse::<Polynomial<GF4>>().unwrap());
+
+        let x1: Polynomial<GF4> = "x + 1".parse().unwrap();
+        let xa: Polynomial<GF4> = "x + a".parse().unwrap();
+        assert!(CrtBasis::new(vec![x1.clone(), x1.mul(&xa)]).is_none());
+        assert!(CrtBasis::new(vec![x1, Polynomial::new()]).is_none());
+    }
+
+    #[test]
+    fn test_crt_roundtrip() {
+        let r = 13;
+        let basis = basis_for_x_pow_minus_one(r);
+        let mut rng = StdRng::seed_from_u64(6);
+        for _ in 0..20 {
+            let p = Polynomial::new_from_coefficients((0..r).map(|_| GF4::generate_random(&mut rng)).collect());
+            let residues = basis.to_residues(&p);
+            assert_eq!(basis.from_residues(&residues), Some(p));
+        }
+        assert!(basis.from_residues(&[]).is_none());
+    }
+
+    #[test]
+    fn test_crt_mul_residues() {
+        let r = 13;
+        let basis = basis_for_x_pow_minus_one(r);
+        let a: Polynomial<GF4> = "a*x^12 + x^7 + (a+1)*x^3 + 1".parse().unwrap();
+        let b: Polynomial<GF4> = "x^11 + a*x^5 + x".parse().unwrap();
+        let product = basis.mul_residues(&basis.to_residues(&a), &basis.to_residues(&b)).unwrap();
+        assert_eq!(basis.from_residues(&product), Some(a.mul(&b).reduce_cyclic(r)));
+    }
+
+    #[test]
+    fn test_crt_degenerate_components() {
+        // a is invertible mod x^r - 1 exactly when no residue vanishes
+        let r = 5;
+        let basis = basis_for_x_pow_minus_one(r);
+        let candidates: Vec<Polynomial<GF4>> = vec![
+            "x^4 + x^3 + x^2 + x + 1".parse().unwrap(),
+            "x + 1".parse().unwrap(),
+            "a*x^3 + x".parse().unwrap(),
+            "x^2 + a*x + 1".parse().unwrap(),
+        ];
+        for a in candidates.iter() {
+            let degenerate = basis.to_residues(a).iter().any(|residue| residue.is_zero());
+            assert_eq!(a.invert(basis.modulus()).is_none(), degenerate, "{}", a);
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use std::ops::Range;
+use crate::galois_fields::GaloisField;
+use crate::polynomials::factorization::field_extension_degree;
+
+fn integer_gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { integer_gcd(b, a % b) }
+}
+
+pub fn is_prime(n: usize) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let mut d = 2;
+    while d * d <= n {
+        if n.is_multiple_of(d) {
+            return false;
+        }
+        d += 1;
+    }
+    true
+}
+
+// Smallest k > 0 with q^k = 1 mod r; None if q is not a unit mod r.
+pub fn multiplicative_order(q: usize, r: usize) -> Option<usize> {
+    if r == 0 || integer_gcd(q % r, r) != 1 {
+        return None;
+    }
+    if r == 1 {
+        return Some(1);
+    }
+    let q = (q % r) as u128;
+    let modulus = r as u128;
+    let mut power = q;
+    let mut k = 1;
+    while power != 1 {
+        power = power * q % modulus;
+        k += 1;
+    }
+    Some(k)
+}
+
+// Partition of {0, ..., r - 1} into orbits i -> i * q mod r, ordered by their
+// smallest element, each starting with it. None if q is not a unit mod r.
+pub fn cyclotomic_cosets(q: usize, r: usize) -> Option<Vec<Vec<usize>>> {
+    multiplicative_order(q, r)?;
+    let mut visited = vec![false; r];
+    let mut cosets = Vec::new();
+    for start in 0..r {
+        if visited[start] {
+            continue;
+        }
+        let mut coset = Vec::new();
+        let mut i = start;
+        while !visited[i] {
+            visited[i] = true;
+            coset.push(i);
+            i = ((i as u128) * (q as u128) % (r as u128)) as usize;
+        }
+        cosets.push(coset);
+    }
+    Some(cosets)
+}
+
+// Degrees and multiplicities of the irreducible factors of x^r - 1 over T.
+// Writing r = p^s * m with p the characteristic and gcd(m, p) = 1, x^r - 1 is
+// (x^m - 1)^(p^s) and the factors of x^m - 1 correspond to the cyclotomic
+// cosets of q mod m, one factor of degree |C| per coset C.
+pub fn factorization_pattern<T: GaloisField>(r: usize) -> Vec<(usize, usize)> {
+    if r == 0 {
+        return Vec::new();
+    }
+    let p = T::characteristic();
+    let mut m = r;
+    let mut multiplicity = 1;
+    while m.is_multiple_of(p) {
+        m /= p;
+        multiplicity *= p;
+    }
+    cyclotomic_cosets(T::order(), m)
+        .unwrap()
+        .iter()
+        .map(|coset| (coset.len(), multiplicity))
+        .collect()
+}
+
+// A prime r, r != p, for which q = p^k has the largest order possible for a
+// k-th power in (Z/rZ)^*, namely (r - 1) / gcd(r - 1, k). Then x^r - 1 is
+// (x - 1) times gcd(r - 1, k) irreducible factors of equal degree; over a
+// prime field that is (x - 1) * (1 + x + ... + x^(r-1)), while over GF(4) the
+// best achievable is (x - 1) times two factors of degree (r - 1) / 2, as 4 is
+// always a square mod r.
+pub fn is_suitable_block_size<T: GaloisField>(r: usize) -> bool {
+    if !is_prime(r) || r == T::characteristic() {
+        return false;
+    }
+    let k = field_extension_degree::<T>();
+    multiplicative_order(T::order(), r) == Some((r - 1) / integer_gcd(r - 1, k))
+}
+
+pub fn suitable_block_sizes<T: GaloisField>(range: Range<usize>) -> Vec<usize> {
+    range.filter(|r| is_suitable_block_size::<T>(*r)).collect()
+}
+
+#[cfg(test)]
+mod cyclotomic_tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use crate::galois_fields::gf4_number::GF4;
+    use crate::polynomials::factorization::factor;
+    use crate::polynomials::polynomial::Polynomial;
+    use super::*;
+
+    #[test]
+    fn test_is_prime() {
+        let primes: Vec<usize> = (0..30).filter(|n| is_prime(*n)).collect();
+        assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    }
+
+    #[test]
+    fn test_multiplicative_order() {
+        assert_eq!(multiplicative_order(4, 5), Some(2));
+        assert_eq!(multiplicative_order(4, 13), Some(6));
+        assert_eq!(multiplicative_order(4, 11), Some(5));
+        assert_eq!(multiplicative_order(2, 11), Some(10));
+        assert_eq!(multiplicative_order(4, 1), Some(1));
+        assert_eq!(multiplicative_order(4, 6), None);
+        assert_eq!(multiplicative_order(4, 0), None);
+    }
+
+    #[test]
+    fn test_cyclotomic_cosets() {
+        assert_eq!(cyclotomic_cosets(4, 5), Some(vec![vec![0], vec![1, 4], vec![2, 3]]));
+        assert_eq!(cyclotomic_cosets(2, 7), Some(vec![vec![0], vec![1, 2, 4], vec![3, 6, 5]]));
+        assert!(cyclotomic_cosets(4, 10).is_none());
+    }
+
+    #[test]
+    fn test_factorization_pattern() {
+        assert_eq!(factorization_pattern::<GF4>(5), vec![(1, 1), (2, 1), (2, 1)]);
+        assert_eq!(factorization_pattern::<GF4>(12), vec![(1, 4), (1, 4), (1, 4)]);
+        assert_eq!(factorization_pattern::<GF4>(3), vec![(1, 1), (1, 1), (1, 1)]);
+
+        // agrees with the actual factorization of x^r - 1
+        let mut rng = StdRng::seed_from_u64(3);
+        for r in 1..=24 {
+            let mut coefficients = vec![GF4::Zero; r + 1];
+            coefficients[0] = GF4::One;
+            coefficients[r] = GF4::One;
+            let mut actual: Vec<(usize, usize)> = factor(&Polynomial::new_from_coefficients(coefficients), &mut rng)
+                .iter()
+                .map(|(f, m)| (f.degree(), *m))
+                .collect();
+            let mut predicted = factorization_pattern::<GF4>(r);
+            actual.sort();
+            predicted.sort();
+            assert_eq!(actual, predicted, "r = {}", r);
+        }
+    }
+
+    #[test]
+    fn test_suitable_block_sizes() {
+        // primes r < 100 for which 4 has order (r - 1) / 2
+        assert_eq!(
+            suitable_block_sizes::<GF4>(0..100),
+            vec![3, 5, 7, 11, 13, 19, 23, 29, 37, 47, 53, 59, 61, 67, 71, 79, 83]
+        );
+        assert!(!is_suitable_block_size::<GF4>(17));
+        assert!(!is_suitable_block_size::<GF4>(2));
+        assert!(!is_suitable_block_size::<GF4>(15));
+
+        for r in suitable_block_sizes::<GF4>(3..100) {
+            let mut pattern = factorization_pattern::<GF4>(r);
+            pattern.sort();
+            if r == 3 {
+                assert_eq!(pattern, vec![(1, 1), (1, 1), (1, 1)]);
+            } else {
+                assert_eq!(pattern, vec![(1, 1), ((r - 1) / 2, 1), ((r - 1) / 2, 1)]);
+            }
+        }
+    }
+}
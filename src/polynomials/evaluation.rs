@@ -0,0 +1,219 @@
+use crate::galois_fields::GaloisField;
+use crate::polynomials::polynomial::Polynomial;
+
+fn linear_factor<T: GaloisField>(root: &T) -> Polynomial<T> {
+    Polynomial::new_from_coefficients(vec![T::generate_zero().sub(root), T::generate_one()])
+}
+
+fn constant<T: GaloisField>(c: T) -> Polynomial<T> {
+    Polynomial::new_from_coefficients(vec![c])
+}
+
+// Binary tree of products of the linear factors (x - x_i). Level 0 holds the
+// leaves; each node of level k + 1 is the product of two neighbouring nodes of
+// level k, an unpaired last node is carried up unchanged.
+pub struct SubproductTree<T: GaloisField> {
+    points: Vec<T>,
+    levels: Vec<Vec<Polynomial<T>>>,
+}
+
+impl<T> SubproductTree<T>
+where T: GaloisField {
+
+    pub fn new(points: &[T]) -> SubproductTree<T> {
+        let mut levels = vec![points.iter().map(linear_factor).collect::<Vec<Polynomial<T>>>()];
+        while levels[levels.len() - 1].len() > 1 {
+            let next = levels[levels.len() - 1]
+                .chunks(2)
+                .map(|pair| if pair.len() == 2 { pair[0].mul(&pair[1]) } else { pair[0].clone() })
+                .collect();
+            levels.push(next);
+        }
+        SubproductTree {
+            points: points.to_vec(),
+            levels,
+        }
+    }
+
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+
+    // The product of all (x - x_i), or one if there are no points.
+    pub fn root(&self) -> Polynomial<T> {
+        match self.levels[self.levels.len() - 1].first() {
+            Some(root) => root.clone(),
+            None => constant(T::generate_one()),
+        }
+    }
+
+    // Reduces poly modulo every node going down the tree and returns the
+    // remainders at the leaves, i.e. poly(x_i).
+    pub fn evaluate(&self, poly: &Polynomial<T>) -> Vec<T> {
+        if self.points.is_empty() {
+            return Vec::new();
+        }
+        let top = self.levels.len() - 1;
+        let mut remainders = vec![poly.div_mod(&self.levels[top][0]).unwrap().1];
+        for level in (0..top).rev() {
+            remainders = self.levels[level]
+                .iter()
+                .enumerate()
+                .map(|(i, node)| remainders[i / 2].div_mod(node).unwrap().1)
+                .collect();
+        }
+        remainders.iter().map(|r| r.get_coefficient(0).unwrap()).collect()
+    }
+
+    // Derivative of the root, built up with the product rule so that no
+    // integer multiples of field elements are needed.
+    fn root_derivative(&self) -> Polynomial<T> {
+        let mut derivatives: Vec<Polynomial<T>> = vec![constant(T::generate_one()); self.points.len()];
+        for level in 0..self.levels.len() - 1 {
+            derivatives = self.levels[level]
+                .chunks(2)
+                .zip(derivatives.chunks(2))
+                .map(|(nodes, ders)| {
+                    if nodes.len() == 2 {
+                        ders[0].mul(&nodes[1]).add(&nodes[0].mul(&ders[1]))
+                    } else {
+                        ders[0].clone()
+                    }
+                })
+                .collect();
+        }
+        derivatives.pop().unwrap_or_default()
+    }
+
+    // Returns the unique polynomial of degree < n through (x_i, values[i]),
+    // or None if the points are not distinct or the lengths differ.
+    pub fn interpolate(&self, values: &[T]) -> Option<Polynomial<T>> {
+        if values.len() != self.points.len() {
+            return None;
+        }
+        if self.points.is_empty() {
+            return Some(Polynomial::new());
+        }
+        let weights = self.evaluate(&self.root_derivative());
+        let mut combined: Vec<Polynomial<T>> = Vec::with_capacity(values.len());
+        for (value, weight) in values.iter().zip(weights.iter()) {
+            combined.push(constant(value.div(weight)?));
+        }
+        for level in 0..self.levels.len() - 1 {
+            combined = self.levels[level]
+                .chunks(2)
+                .zip(combined.chunks(2))
+                .map(|(nodes, parts)| {
+                    if nodes.len() == 2 {
+                        parts[0].mul(&nodes[1]).add(&parts[1].mul(&nodes[0]))
+                    } else {
+                        parts[0].clone()
+                    }
+                })
+                .collect();
+        }
+        combined.pop()
+    }
+}
+
+pub fn multipoint_evaluate<T: GaloisField>(poly: &Polynomial<T>, points: &[T]) -> Vec<T> {
+    SubproductTree::new(points).evaluate(poly)
+}
+
+// O(n^2) Lagrange interpolation; None if two points share an x coordinate.
+pub fn lagrange_interpolate<T: GaloisField>(points: &[(T, T)]) -> Option<Polynomial<T>> {
+    let mut result = Polynomial::new();
+    for (i, (xi, yi)) in points.iter().enumerate() {
+        let mut basis = constant(T::generate_one());
+        let mut denominator = T::generate_one();
+        for (j, (xj, _)) in points.iter().enumerate() {
+            if i != j {
+                basis = basis.mul(&linear_factor(xj));
+                denominator = denominator.mul(&xi.sub(xj));
+            }
+        }
+        result = result.add(&basis.mul(&constant(yi.div(&denominator)?)));
+    }
+    Some(result)
+}
+
+// Interpolation through the subproduct tree; None if two points share an x coordinate.
+pub fn fast_interpolate<T: GaloisField>(points: &[(T, T)]) -> Option<Polynomial<T>> {
+    let xs: Vec<T> = points.iter().map(|(x, _)| x.clone()).collect();
+    let ys: Vec<T> = points.iter().map(|(_, y)| y.clone()).collect();
+    SubproductTree::new(&xs).interpolate(&ys)
+}
+
+#[cfg(test)]
+mod evaluation_tests {
+    use crate::galois_fields::gf4_number::GF4;
+    use super::*;
+
+    fn sample_polynomial() -> Polynomial<GF4> {
+        // a*x^3 + x^2 + (a+1)
+        Polynomial::new_from_coefficients(vec![
+            GF4::AlphaPlusOne, GF4::Zero, GF4::One, GF4::Alpha
+        ])
+    }
+
+    #[test]
+    fn test_subproduct_tree_root() {
+        let tree = SubproductTree::new(&GF4::elements());
+        // the product over all x in GF(4) of (x - c) is x^4 - x
+        assert_eq!(tree.root(), Polynomial::new_from_coefficients(vec![
+            GF4::Zero, GF4::One, GF4::Zero, GF4::Zero, GF4::One
+        ]));
+
+        let tree = SubproductTree::new(&[GF4::One, GF4::Alpha, GF4::AlphaPlusOne]);
+        assert_eq!(tree.root(), Polynomial::new_from_coefficients(vec![
+            GF4::One, GF4::Zero, GF4::Zero, GF4::One
+        ]));
+        assert!(SubproductTree::<GF4>::new(&[]).root().is_one());
+    }
+
+    #[test]
+    fn test_multipoint_evaluate() {
+        let p = sample_polynomial();
+        assert_eq!(multipoint_evaluate(&p, &GF4::elements()), p.evaluate_all());
+
+        let points = vec![GF4::Alpha, GF4::Zero, GF4::Alpha];
+        let expected: Vec<GF4> = points.iter().map(|x| p.evaluate(x)).collect();
+        assert_eq!(multipoint_evaluate(&p, &points), expected);
+        assert!(multipoint_evaluate(&p, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_lagrange_interpolate() {
+        let p = sample_polynomial();
+        let points: Vec<(GF4, GF4)> = GF4::elements().into_iter().map(|x| {
+            let y = p.evaluate(&x);
+            (x, y)
+        }).collect();
+        assert_eq!(lagrange_interpolate(&points), Some(p));
+
+        let duplicate = vec![(GF4::One, GF4::One), (GF4::One, GF4::Alpha)];
+        assert!(lagrange_interpolate(&duplicate).is_none());
+        assert_eq!(lagrange_interpolate::<GF4>(&[]), Some(Polynomial::new()));
+    }
+
+    #[test]
+    fn test_fast_interpolate() {
+        let p = sample_polynomial();
+        let points: Vec<(GF4, GF4)> = GF4::elements().into_iter().map(|x| {
+            let y = p.evaluate(&x);
+            (x, y)
+        }).collect();
+        assert_eq!(fast_interpolate(&points), Some(p));
+
+        let points = vec![(GF4::Alpha, GF4::One), (GF4::Zero, GF4::AlphaPlusOne), (GF4::One, GF4::Zero)];
+        let lagrange = lagrange_interpolate(&points).unwrap();
+        assert_eq!(fast_interpolate(&points), Some(lagrange.clone()));
+        for (x, y) in points.iter() {
+            assert_eq!(&lagrange.evaluate(x), y);
+        }
+
+        let duplicate = vec![(GF4::One, GF4::One), (GF4::Zero, GF4::One), (GF4::One, GF4::Alpha)];
+        assert!(fast_interpolate(&duplicate).is_none());
+        assert!(SubproductTree::new(&[GF4::One]).interpolate(&[]).is_none());
+    }
+}
@@ -0,0 +1,366 @@
+use rand::Rng;
+use crate::galois_fields::GaloisField;
+use crate::polynomials::polynomial::Polynomial;
+use crate::polynomials::polynomial_operations::gcd;
+
+fn x<T: GaloisField>() -> Polynomial<T> {
+    Polynomial::new_from_coefficients(vec![T::generate_zero(), T::generate_one()])
+}
+
+// Returns k such that q = p^k.
+pub(crate) fn field_extension_degree<T: GaloisField>() -> usize {
+    let p = T::characteristic();
+    let mut q = T::order();
+    let mut k = 0;
+    while q > 1 {
+        q /= p;
+        k += 1;
+    }
+    k
+}
+
+// p-th root of a polynomial whose derivative is zero, i.e. f(x) = g(x)^p.
+// Coefficients are mapped through a -> a^(q/p), the inverse of a -> a^p.
+fn pth_root<T: GaloisField>(poly: &Polynomial<T>) -> Polynomial<T> {
+    let p = T::characteristic();
+    let root_exponent = T::order() / p;
+    let coefficients = (0..=poly.degree() / p)
+        .map(|i| poly.get_coefficient(i * p).unwrap().pow(root_exponent))
+        .collect();
+    Polynomial::new_from_coefficients(coefficients)
+}
+
+pub(crate) fn prime_divisors(n: usize) -> Vec<usize> {
+    let mut divisors = Vec::new();
+    let mut m = n;
+    let mut d = 2;
+    while d * d <= m {
+        if m.is_multiple_of(d) {
+            divisors.push(d);
+            while m.is_multiple_of(d) {
+                m /= d;
+            }
+        }
+        d += 1;
+    }
+    if m > 1 {
+        divisors.push(m);
+    }
+    divisors
+}
+
+fn exact_div<T: GaloisField>(a: &Polynomial<T>, b: &Polynomial<T>) -> Polynomial<T> {
+    a.div_mod(b).unwrap().0
+}
+
+// Splits a monic polynomial into pairwise coprime square-free factors, returned
+// with the multiplicity they appear with in the input. Constants yield no factors.
+pub fn square_free_factorization<T: GaloisField>(poly: &Polynomial<T>) -> Vec<(Polynomial<T>, usize)> {
+    let f = poly.monic();
+    if f.degree() == 0 {
+        return Vec::new();
+    }
+    let p = T::characteristic();
+    let derivative = f.derivative();
+    if derivative.is_zero() {
+        return square_free_factorization(&pth_root(&f))
+            .into_iter()
+            .map(|(factor, multiplicity)| (factor, multiplicity * p))
+            .collect();
+    }
+
+    let mut factors = Vec::new();
+    let mut c = gcd(&f, &derivative);
+    let mut w = exact_div(&f, &c);
+    let mut i = 1;
+    while !w.is_one() {
+        let y = gcd(&w, &c);
+        let factor = exact_div(&w, &y);
+        if !factor.is_one() {
+            factors.push((factor, i));
+        }
+        w = y;
+        c = exact_div(&c, &w);
+        i += 1;
+    }
+    if !c.is_one() {
+        factors.extend(
+            square_free_factorization(&pth_root(&c))
+                .into_iter()
+                .map(|(factor, multiplicity)| (factor, multiplicity * p))
+        );
+    }
+    factors
+}
+
+// Splits a monic square-free polynomial into products of irreducible factors
+// of equal degree. Each entry is (product, degree of its irreducible factors).
+pub fn distinct_degree_factorization<T: GaloisField>(poly: &Polynomial<T>) -> Vec<(Polynomial<T>, usize)> {
+    let q = T::order();
+    let mut factors = Vec::new();
+    let mut rest = poly.monic();
+    let mut h = x();
+    let mut i = 1;
+    while rest.degree() >= 2 * i {
+        // h = x^(q^i) mod rest
+        h = h.pow_mod(q, &rest).unwrap();
+        let g = gcd(&rest, &h.sub(&x()));
+        if !g.is_one() {
+            rest = exact_div(&rest, &g);
+            h = h.div_mod(&rest).unwrap().1;
+            factors.push((g, i));
+        }
+        i += 1;
+    }
+    if rest.degree() > 0 {
+        let degree = rest.degree();
+        factors.push((rest, degree));
+    }
+    factors
+}
+
+// Cantor-Zassenhaus splitting map: the trace into GF(2) for characteristic 2,
+// h^((q^d - 1) / 2) - 1 otherwise. Exponents are formed from repeated q-th
+// powers so that they never overflow.
+fn splitting_polynomial<T: GaloisField>(h: &Polynomial<T>, degree: usize, modulus: &Polynomial<T>) -> Polynomial<T> {
+    let q = T::order();
+    if T::characteristic() == 2 {
+        let mut term = h.clone();
+        let mut trace = h.clone();
+        for _ in 1..(field_extension_degree::<T>() * degree) {
+            term = term.pow_mod(2, modulus).unwrap();
+            trace = trace.add(&term);
+        }
+        trace
+    } else {
+        let mut term = h.clone();
+        let mut norm = h.clone();
+        for _ in 1..degree {
+            term = term.pow_mod(q, modulus).unwrap();
+            norm = norm.mul(&term).div_mod(modulus).unwrap().1;
+        }
+        norm.pow_mod((q - 1) / 2, modulus).unwrap()
+            .sub(&Polynomial::new_from_coefficients(vec![T::generate_one()]))
+    }
+}
+
+// Random splitting attempts allowed per expected factor. Each attempt
+// separates any two distinct factors with probability about 1/2, so a valid
+// input fails to split within the bound with negligible probability.
+const SPLITTING_ATTEMPTS_PER_FACTOR: usize = 64;
+
+// Splits a monic square-free polynomial whose irreducible factors all have the
+// given degree into those factors (Cantor-Zassenhaus). None if degree does not
+// divide deg poly, or if the polynomial does not split within the attempt
+// bound, which means it has an irreducible factor of another degree.
+pub fn equal_degree_factorization<T: GaloisField, R: Rng + ?Sized>(
+    poly: &Polynomial<T>,
+    degree: usize,
+    rng: &mut R,
+) -> Option<Vec<Polynomial<T>>> {
+    let f = poly.monic();
+    if f.degree() == 0 {
+        return Some(Vec::new());
+    }
+    if degree == 0 || !f.degree().is_multiple_of(degree) {
+        return None;
+    }
+    let count = f.degree() / degree;
+    let mut factors = vec![f.clone()];
+    let mut attempts = 0;
+    while factors.len() < count {
+        if attempts == SPLITTING_ATTEMPTS_PER_FACTOR * count {
+            return None;
+        }
+        attempts += 1;
+        let coefficients = (0..f.degree()).map(|_| T::generate_random(rng)).collect();
+        let h = Polynomial::new_from_coefficients(coefficients);
+        let g = splitting_polynomial(&h, degree, &f);
+
+        let mut next = Vec::new();
+        for u in factors {
+            if u.degree() > degree {
+                let d = gcd(&g, &u);
+                if !d.is_one() && d != u {
+                    next.push(exact_div(&u, &d));
+                    next.push(d);
+                    continue;
+                }
+            }
+            next.push(u);
+        }
+        factors = next;
+    }
+    Some(factors)
+}
+
+// Monic irreducible factors of poly with multiplicities, sorted by degree.
+// The leading coefficient is dropped; constants yield no factors.
+pub fn factor<T: GaloisField, R: Rng + ?Sized>(poly: &Polynomial<T>, rng: &mut R) -> Vec<(Polynomial<T>, usize)> {
+    let mut factors = Vec::new();
+    for (square_free, multiplicity) in square_free_factorization(poly) {
+        for (product, degree) in distinct_degree_factorization(&square_free) {
+            // distinct-degree factors meet the precondition
+            for irreducible in equal_degree_factorization(&product, degree, rng).unwrap() {
+                factors.push((irreducible, multiplicity));
+            }
+        }
+    }
+    factors.sort_by_key(|(f, _)| f.degree());
+    factors
+}
+
+// Rabin's irreducibility test.
+pub fn is_irreducible<T: GaloisField>(poly: &Polynomial<T>) -> bool {
+    let n = poly.degree();
+    if n == 0 {
+        return false;
+    }
+    let f = poly.monic();
+    let q = T::order();
+    // powers[i] = x^(q^i) mod f
+    let mut powers = vec![x::<T>().div_mod(&f).unwrap().1];
+    for i in 1..=n {
+        let next = powers[i - 1].pow_mod(q, &f).unwrap();
+        powers.push(next);
+    }
+    if powers[n] != powers[0] {
+        return false;
+    }
+    prime_divisors(n)
+        .iter()
+        .all(|p| gcd(&f, &powers[n / p].sub(&x())).is_one())
+}
+
+#[cfg(test)]
+mod factorization_tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use crate::galois_fields::gf4_number::GF4;
+    use super::*;
+
+    fn linear(c: GF4) -> Polynomial<GF4> {
+        Polynomial::new_from_coefficients(vec![c, GF4::One])
+    }
+
+    fn x_pow_minus_one(r: usize) -> Polynomial<GF4> {
+        let mut coefficients = vec![GF4::Zero; r + 1];
+        coefficients[0] = GF4::One;
+        coefficients[r] = GF4::One;
+        Polynomial::new_from_coefficients(coefficients)
+    }
+
+    fn product(factors: &[(Polynomial<GF4>, usize)]) -> Polynomial<GF4> {
+        factors.iter().fold(
+            Polynomial::new_from_coefficients(vec![GF4::One]),
+            |acc, (f, m)| acc.mul(&f.pow(*m)),
+        )
+    }
+
+    #[test]
+    fn test_pth_root() {
+        let p = Polynomial::new_from_coefficients(vec![GF4::Alpha, GF4::One, GF4::AlphaPlusOne]);
+        assert_eq!(pth_root(&p.pow(2)), p);
+    }
+
+    #[test]
+    fn test_square_free_factorization() {
+        // (x + 1)^4 (x + a)^2 (x^2 + x + a) (x + a + 1)
+        let irreducible = Polynomial::new_from_coefficients(vec![GF4::Alpha, GF4::One, GF4::One]);
+        let f = linear(GF4::One).pow(4)
+            .mul(&linear(GF4::Alpha).pow(2))
+            .mul(&irreducible)
+            .mul(&linear(GF4::AlphaPlusOne))
+            .scale(&GF4::Alpha);
+
+        let factors = square_free_factorization(&f);
+        assert_eq!(product(&factors), f.monic());
+        assert!(factors.contains(&(irreducible.mul(&linear(GF4::AlphaPlusOne)), 1)));
+        assert!(factors.contains(&(linear(GF4::Alpha), 2)));
+        assert!(factors.contains(&(linear(GF4::One), 4)));
+        assert_eq!(factors.len(), 3);
+        assert!(square_free_factorization(&Polynomial::new_from_coefficients(vec![GF4::Alpha])).is_empty());
+    }
+
+    #[test]
+    fn test_distinct_degree_factorization() {
+        // x^5 - 1 = (x + 1)(x^2 + a*x + 1)(x^2 + (a+1)*x + 1)
+        let f = x_pow_minus_one(5);
+        let factors = distinct_degree_factorization(&f);
+        assert_eq!(factors, vec![
+            (linear(GF4::One), 1),
+            (exact_div(&f, &linear(GF4::One)), 2),
+        ]);
+    }
+
+    #[test]
+    fn test_equal_degree_factorization() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let f = exact_div(&x_pow_minus_one(5), &linear(GF4::One));
+        let factors = equal_degree_factorization(&f, 2, &mut rng).unwrap();
+        assert_eq!(factors.len(), 2);
+        assert!(factors.contains(&Polynomial::new_from_coefficients(vec![GF4::One, GF4::Alpha, GF4::One])));
+        assert!(factors.contains(&Polynomial::new_from_coefficients(vec![GF4::One, GF4::AlphaPlusOne, GF4::One])));
+
+        // x^4 - x splits into all linear factors
+        let f = Polynomial::new_from_coefficients(vec![GF4::Zero, GF4::One, GF4::Zero, GF4::Zero, GF4::One]);
+        let factors = equal_degree_factorization(&f, 1, &mut rng).unwrap();
+        assert_eq!(factors.len(), 4);
+        for c in GF4::elements() {
+            assert!(factors.contains(&linear(c)));
+        }
+        assert_eq!(equal_degree_factorization(&Polynomial::new_from_coefficients(vec![GF4::Alpha]), 2, &mut rng), Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_equal_degree_factorization_precondition() {
+        let mut rng = StdRng::seed_from_u64(3);
+        // linear times irreducible quadratic: 2 does not divide 3
+        let irreducible = Polynomial::new_from_coefficients(vec![GF4::Alpha, GF4::One, GF4::One]);
+        let f = linear(GF4::One).mul(&irreducible);
+        assert_eq!(equal_degree_factorization(&f, 2, &mut rng), None);
+        assert_eq!(equal_degree_factorization(&f, 0, &mut rng), None);
+
+        // x^7 - 1 = (x - 1) * (two irreducible cubics) since ord_7(4) = 3
+        let cubics = exact_div(&x_pow_minus_one(7), &linear(GF4::One));
+        let cubic = equal_degree_factorization(&cubics, 3, &mut rng).unwrap().remove(0);
+        assert!(is_irreducible(&cubic));
+        // an irreducible has no linear factors to split off
+        assert_eq!(equal_degree_factorization(&cubic, 1, &mut rng), None);
+        // and two cubics never split into three quadratics
+        assert_eq!(equal_degree_factorization(&cubics, 2, &mut rng), None);
+    }
+
+    #[test]
+    fn test_factor() {
+        let mut rng = StdRng::seed_from_u64(2);
+        for r in [3, 5, 7, 11, 12, 13, 17] {
+            let f = x_pow_minus_one(r);
+            let factors = factor(&f, &mut rng);
+            assert_eq!(product(&factors), f);
+            for (g, _) in factors.iter() {
+                assert!(is_irreducible(g));
+                assert!(g.leading_coefficient().is_one());
+            }
+        }
+
+        // x^12 - 1 = (x^3 - 1)^4, which has three linear factors over GF(4)
+        let factors = factor(&x_pow_minus_one(12), &mut rng);
+        assert_eq!(factors.len(), 3);
+        assert!(factors.iter().all(|(g, m)| g.degree() == 1 && *m == 4));
+
+        // x^13 - 1 = (x - 1) * (two irreducible sextics) since ord_13(4) = 6
+        let degrees: Vec<usize> = factor(&x_pow_minus_one(13), &mut rng).iter().map(|(g, _)| g.degree()).collect();
+        assert_eq!(degrees, vec![1, 6, 6]);
+    }
+
+    #[test]
+    fn test_is_irreducible() {
+        assert!(is_irreducible(&linear(GF4::Alpha)));
+        assert!(is_irreducible(&Polynomial::new_from_coefficients(vec![GF4::Alpha, GF4::One, GF4::One])));
+        assert!(!is_irreducible(&Polynomial::new_from_coefficients(vec![GF4::One, GF4::One, GF4::One])));
+        assert!(!is_irreducible(&x_pow_minus_one(5)));
+        assert!(!is_irreducible(&linear(GF4::One).pow(2)));
+        assert!(!is_irreducible(&Polynomial::new_from_coefficients(vec![GF4::Alpha])));
+    }
+}
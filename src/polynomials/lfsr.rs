@@ -0,0 +1,174 @@
+use std::collections::VecDeque;
+use crate::galois_fields::GaloisField;
+use crate::polynomials::polynomial::Polynomial;
+
+// Connection polynomials are written C(x) = 1 + c_1 x + ... + c_L x^L and
+// describe the recurrence s_n = -(c_1 s_{n-1} + ... + c_L s_{n-L}).
+
+// Berlekamp-Massey: the shortest LFSR generating the sequence. Returns its
+// connection polynomial and the linear complexity L; deg C(x) may be below L.
+pub fn berlekamp_massey<T: GaloisField>(sequence: &[T]) -> (Polynomial<T>, usize) {
+    let one = Polynomial::new_from_coefficients(vec![T::generate_one()]);
+    let mut c = one.clone();
+    let mut b = one;
+    let mut length = 0;
+    let mut m = 1;
+    let mut last_discrepancy = T::generate_one();
+
+    for n in 0..sequence.len() {
+        let mut discrepancy = sequence[n].clone();
+        for i in 1..=length {
+            let ci = c.get_coefficient(i).unwrap_or(T::generate_zero());
+            discrepancy = discrepancy.add(&ci.mul(&sequence[n - i]));
+        }
+
+        if discrepancy.is_zero() {
+            m += 1;
+            continue;
+        }
+        let factor = discrepancy.div(&last_discrepancy).unwrap();
+        let correction = b.shift(m).scale(&factor);
+        if 2 * length <= n {
+            let previous = c.clone();
+            c = c.sub(&correction);
+            length = n + 1 - length;
+            b = previous;
+            last_discrepancy = discrepancy;
+            m = 1;
+        } else {
+            c = c.sub(&correction);
+            m += 1;
+        }
+    }
+    (c, length)
+}
+
+pub fn linear_complexity<T: GaloisField>(sequence: &[T]) -> usize {
+    berlekamp_massey(sequence).1
+}
+
+#[derive(Debug, Clone)]
+pub struct Lfsr<T: GaloisField> {
+    // c_1, ..., c_L
+    taps: Vec<T>,
+    // the next L output symbols, oldest first
+    state: VecDeque<T>,
+}
+
+impl<T> Lfsr<T>
+where T: GaloisField {
+
+    // The register length is initial_state.len(). None if C(0) != 1 or the
+    // connection polynomial has higher degree than the register length.
+    pub fn new(connection: &Polynomial<T>, initial_state: Vec<T>) -> Option<Lfsr<T>> {
+        if !connection.get_coefficient(0).unwrap().is_one() || connection.degree() > initial_state.len() {
+            return None;
+        }
+        let taps = (1..=initial_state.len())
+            .map(|i| connection.get_coefficient(i).unwrap_or(T::generate_zero()))
+            .collect();
+        Some(Lfsr {
+            taps,
+            state: initial_state.into(),
+        })
+    }
+
+    pub fn length(&self) -> usize {
+        self.taps.len()
+    }
+
+    pub fn connection_polynomial(&self) -> Polynomial<T> {
+        let mut coefficients = vec![T::generate_one()];
+        coefficients.extend_from_slice(&self.taps);
+        Polynomial::new_from_coefficients(coefficients)
+    }
+
+    pub fn next_symbol(&mut self) -> T {
+        let length = self.taps.len();
+        if length == 0 {
+            return T::generate_zero();
+        }
+        let mut feedback = T::generate_zero();
+        for (i, tap) in self.taps.iter().enumerate() {
+            // s_{n-1-i} sits at position L - 1 - i of the state
+            feedback = feedback.sub(&tap.mul(&self.state[length - 1 - i]));
+        }
+        self.state.push_back(feedback);
+        self.state.pop_front().unwrap()
+    }
+
+    pub fn generate(&mut self, count: usize) -> Vec<T> {
+        (0..count).map(|_| self.next_symbol()).collect()
+    }
+}
+
+impl<T> Iterator for Lfsr<T>
+where T: GaloisField {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        Some(self.next_symbol())
+    }
+}
+
+#[cfg(test)]
+mod lfsr_tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use crate::galois_fields::gf4_number::GF4;
+    use super::*;
+
+    #[test]
+    fn test_lfsr_generate() {
+        // s_n = s_{n-1} + a*s_{n-2}
+        let connection: Polynomial<GF4> = "a*x^2 + x + 1".parse().unwrap();
+        let mut lfsr = Lfsr::new(&connection, vec![GF4::One, GF4::Zero]).unwrap();
+        assert_eq!(lfsr.length(), 2);
+        assert_eq!(lfsr.connection_polynomial(), connection);
+        assert_eq!(lfsr.generate(6), vec![
+            GF4::One, GF4::Zero, GF4::Alpha, GF4::Alpha, GF4::One, GF4::Alpha
+        ]);
+
+        let from_iterator: Vec<GF4> = Lfsr::new(&connection, vec![GF4::One, GF4::Zero]).unwrap().take(6).collect();
+        assert_eq!(from_iterator[2..], [GF4::Alpha, GF4::Alpha, GF4::One, GF4::Alpha]);
+
+        assert!(Lfsr::new(&"x + a".parse().unwrap(), vec![GF4::One]).is_none());
+        assert!(Lfsr::new(&connection, vec![GF4::One]).is_none());
+        assert!(Lfsr::new(&connection, vec![GF4::One, GF4::Zero, GF4::Alpha]).is_some());
+    }
+
+    #[test]
+    fn test_berlekamp_massey() {
+        let connection: Polynomial<GF4> = "a*x^2 + x + 1".parse().unwrap();
+        let sequence = Lfsr::new(&connection, vec![GF4::One, GF4::Zero]).unwrap().generate(10);
+        assert_eq!(berlekamp_massey(&sequence), (connection, 2));
+
+        // an impulse at position k has linear complexity k + 1
+        let impulse = vec![GF4::Zero, GF4::Zero, GF4::Zero, GF4::Alpha];
+        assert_eq!(linear_complexity(&impulse), 4);
+
+        let (c, l) = berlekamp_massey(&vec![GF4::Zero; 5]);
+        assert!(c.is_one());
+        assert_eq!(l, 0);
+        assert_eq!(berlekamp_massey::<GF4>(&[]), (Polynomial::new_from_coefficients(vec![GF4::One]), 0));
+    }
+
+    #[test]
+    fn test_berlekamp_massey_regenerates_sequence() {
+        let mut rng = StdRng::seed_from_u64(4);
+        for length in 1..8 {
+            for _ in 0..20 {
+                let mut taps = vec![GF4::One];
+                taps.extend((0..length).map(|_| GF4::generate_random(&mut rng)));
+                let connection = Polynomial::new_from_coefficients(taps);
+                let initial: Vec<GF4> = (0..length).map(|_| GF4::generate_random(&mut rng)).collect();
+                let sequence = Lfsr::new(&connection, initial).unwrap().generate(3 * length);
+
+                let (found, l) = berlekamp_massey(&sequence);
+                assert!(l <= length);
+                let regenerated = Lfsr::new(&found, sequence[..l].to_vec()).unwrap().generate(sequence.len());
+                assert_eq!(regenerated, sequence);
+            }
+        }
+    }
+}
@@ -1,2 +1,12 @@
 pub mod polynomial;
-pub mod polynomial_operations;
\ No newline at end of file
+pub mod polynomial_operations;
+pub mod evaluation;
+pub mod factorization;
+pub mod cyclotomic;
+pub mod parsing;
+pub mod workspace;
+pub mod lfsr;
+pub mod crt;
+pub mod resultant;
+pub mod polynomial_matrix;
+pub mod sparse_polynomial;
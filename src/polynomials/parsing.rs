@@ -0,0 +1,325 @@
+use std::fmt;
+use std::str::FromStr;
+use crate::galois_fields::GaloisField;
+use crate::polynomials::polynomial::Polynomial;
+
+// Terms are written from the highest degree down, e.g. a*x^4 + (a+1)*x^2 + 1.
+// Coefficients equal to one are omitted and coefficients whose own notation
+// contains a sum are parenthesized. The alternate flag ({:#}) is passed on to
+// the coefficients, which for GF4 selects the ω notation.
+impl<T> fmt::Display for Polynomial<T>
+where T: GaloisField + fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format_coefficient = |c: &T| if f.alternate() { format!("{:#}", c) } else { format!("{}", c) };
+        if self.is_zero() {
+            return f.write_str(&format_coefficient(&T::generate_zero()));
+        }
+
+        let mut terms = Vec::new();
+        for i in (0..=self.degree()).rev() {
+            let c = self.get_coefficient(i).unwrap();
+            if c.is_zero() {
+                continue;
+            }
+            let mut coefficient = format_coefficient(&c);
+            if coefficient.contains(['+', '-', ' ']) {
+                coefficient = format!("({})", coefficient);
+            }
+            let term = match (i, c.is_one()) {
+                (0, _) => coefficient,
+                (1, true) => "x".to_string(),
+                (1, false) => format!("{}*x", coefficient),
+                (_, true) => format!("x^{}", i),
+                (_, false) => format!("{}*x^{}", coefficient, i),
+            };
+            terms.push(term);
+        }
+        f.write_str(&terms.join(" + "))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePolynomialErrorKind {
+    UnexpectedEnd,
+    UnexpectedCharacter(char),
+    UnclosedParenthesis,
+    InvalidCoefficient(String),
+    InvalidExponent(String),
+}
+
+// position is the offset in characters (not bytes) of the offending input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePolynomialError {
+    pub position: usize,
+    pub kind: ParsePolynomialErrorKind,
+}
+
+impl fmt::Display for ParsePolynomialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParsePolynomialErrorKind::UnexpectedEnd =>
+                write!(f, "unexpected end of input at position {}", self.position),
+            ParsePolynomialErrorKind::UnexpectedCharacter(c) =>
+                write!(f, "unexpected character '{}' at position {}", c, self.position),
+            ParsePolynomialErrorKind::UnclosedParenthesis =>
+                write!(f, "unclosed parenthesis at position {}", self.position),
+            ParsePolynomialErrorKind::InvalidCoefficient(s) =>
+                write!(f, "invalid coefficient '{}' at position {}", s, self.position),
+            ParsePolynomialErrorKind::InvalidExponent(s) =>
+                write!(f, "invalid exponent '{}' at position {}", s, self.position),
+        }
+    }
+}
+
+impl std::error::Error for ParsePolynomialError {}
+
+// Largest exponent FromStr accepts. Parsing x^n allocates n + 1
+// coefficients, so unbounded exponents would let a short string exhaust
+// memory; larger ones are rejected with InvalidExponent.
+pub const MAX_PARSED_EXPONENT: usize = 1 << 24;
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn error<R>(&self, position: usize, kind: ParsePolynomialErrorKind) -> Result<R, ParsePolynomialError> {
+        Err(ParsePolynomialError { position, kind })
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    // A maximal run of characters that are not operators, parentheses or whitespace.
+    fn atom(&mut self) -> String {
+        let start = self.position;
+        while self.peek().is_some_and(|c| !"+-*()".contains(c) && !c.is_whitespace()) {
+            self.position += 1;
+        }
+        self.chars[start..self.position].iter().collect()
+    }
+
+    fn unexpected<R>(&self) -> Result<R, ParsePolynomialError> {
+        match self.peek() {
+            Some(c) => self.error(self.position, ParsePolynomialErrorKind::UnexpectedCharacter(c)),
+            None => self.error(self.position, ParsePolynomialErrorKind::UnexpectedEnd),
+        }
+    }
+
+    fn coefficient<T: FromStr>(&self, text: &str, position: usize) -> Result<T, ParsePolynomialError> {
+        match text.parse::<T>() {
+            Ok(c) => Ok(c),
+            Err(_) => self.error(position, ParsePolynomialErrorKind::InvalidCoefficient(text.to_string())),
+        }
+    }
+
+    // Parses x or x^n from an atom that starts at the given position.
+    fn exponent(&self, atom: &str, position: usize) -> Result<Option<usize>, ParsePolynomialError> {
+        if atom == "x" {
+            return Ok(Some(1));
+        }
+        match atom.strip_prefix("x^") {
+            Some(digits) => match digits.parse::<usize>() {
+                Ok(n) if n <= MAX_PARSED_EXPONENT => Ok(Some(n)),
+                _ => self.error(position + 2, ParsePolynomialErrorKind::InvalidExponent(digits.to_string())),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn monomial(&mut self) -> Result<usize, ParsePolynomialError> {
+        self.skip_whitespace();
+        let start = self.position;
+        let atom = self.atom();
+        if atom.is_empty() {
+            return self.unexpected();
+        }
+        match self.exponent(&atom, start)? {
+            Some(n) => Ok(n),
+            None => self.error(start, ParsePolynomialErrorKind::UnexpectedCharacter(atom.chars().next().unwrap())),
+        }
+    }
+
+    // term := '(' coefficient ')' ['*' monomial] | coefficient ['*' monomial] | monomial
+    fn term<T: GaloisField + FromStr>(&mut self) -> Result<Polynomial<T>, ParsePolynomialError> {
+        self.skip_whitespace();
+        let start = self.position;
+        let coefficient: T = if self.peek() == Some('(') {
+            let mut depth = 0;
+            loop {
+                match self.peek() {
+                    Some('(') => depth += 1,
+                    Some(')') => depth -= 1,
+                    Some(_) => {}
+                    None => return self.error(start, ParsePolynomialErrorKind::UnclosedParenthesis),
+                }
+                self.position += 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            let inner: String = self.chars[start + 1..self.position - 1].iter().collect();
+            self.coefficient(&inner, start + 1)?
+        } else {
+            let atom = self.atom();
+            if atom.is_empty() {
+                return self.unexpected();
+            }
+            if let Some(n) = self.exponent(&atom, start)? {
+                return Ok(Polynomial::new_from_coefficients(vec![T::generate_one()]).shift(n));
+            }
+            self.coefficient(&atom, start)?
+        };
+
+        self.skip_whitespace();
+        let constant = Polynomial::new_from_coefficients(vec![coefficient]);
+        if self.peek() == Some('*') {
+            self.position += 1;
+            let n = self.monomial()?;
+            Ok(constant.shift(n))
+        } else {
+            Ok(constant)
+        }
+    }
+
+    // polynomial := ['-'] term (('+' | '-') term)*
+    fn polynomial<T: GaloisField + FromStr>(&mut self) -> Result<Polynomial<T>, ParsePolynomialError> {
+        let mut result = Polynomial::new();
+        self.skip_whitespace();
+        let mut negate = if self.peek() == Some('-') {
+            self.position += 1;
+            true
+        } else {
+            false
+        };
+        loop {
+            let term = self.term::<T>()?;
+            result = if negate { result.sub(&term) } else { result.add(&term) };
+            self.skip_whitespace();
+            match self.peek() {
+                None => return Ok(result),
+                Some('+') => negate = false,
+                Some('-') => negate = true,
+                Some(_) => return self.unexpected(),
+            }
+            self.position += 1;
+        }
+    }
+}
+
+// Parses the notation produced by Display. Terms may appear in any order and
+// repeated powers are summed; - is accepted as subtraction.
+impl<T> FromStr for Polynomial<T>
+where T: GaloisField + FromStr {
+    type Err = ParsePolynomialError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            chars: s.chars().collect(),
+            position: 0,
+        };
+        parser.polynomial()
+    }
+}
+
+#[cfg(test)]
+mod parsing_tests {
+    use crate::galois_fields::gf4_number::GF4;
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let p = Polynomial::new_from_coefficients(vec![
+            GF4::One, GF4::Zero, GF4::AlphaPlusOne, GF4::Zero, GF4::Alpha
+        ]);
+        assert_eq!(format!("{}", p), "a*x^4 + (a+1)*x^2 + 1");
+        assert_eq!(format!("{:#}", p), "ω*x^4 + ω²*x^2 + 1");
+
+        let p = Polynomial::new_from_coefficients(vec![GF4::AlphaPlusOne, GF4::One, GF4::One]);
+        assert_eq!(format!("{}", p), "x^2 + x + (a+1)");
+        assert_eq!(format!("{}", Polynomial::<GF4>::new()), "0");
+        assert_eq!(format!("{}", Polynomial::new_from_coefficients(vec![GF4::Zero, GF4::Alpha])), "a*x");
+    }
+
+    #[test]
+    fn test_from_str() {
+        let expected = Polynomial::new_from_coefficients(vec![
+            GF4::One, GF4::Zero, GF4::AlphaPlusOne, GF4::Zero, GF4::Alpha
+        ]);
+        assert_eq!("a*x^4 + (a+1)*x^2 + 1".parse::<Polynomial<GF4>>(), Ok(expected.clone()));
+        assert_eq!("ω*x^4 + ω²*x^2 + 1".parse::<Polynomial<GF4>>(), Ok(expected.clone()));
+        assert_eq!("1+w^2*x^2+w*x^4".parse::<Polynomial<GF4>>(), Ok(expected.clone()));
+        // a + 1 at the top level is a sum of two constant terms
+        assert_eq!("x^2 + a*x^4 + a*x^2 + a + 1 + a".parse::<Polynomial<GF4>>(), Ok(expected));
+
+        assert_eq!("x".parse::<Polynomial<GF4>>(), Ok(Polynomial::new_from_coefficients(vec![GF4::Zero, GF4::One])));
+        assert_eq!("0".parse::<Polynomial<GF4>>(), Ok(Polynomial::new()));
+        assert_eq!("x - x".parse::<Polynomial<GF4>>(), Ok(Polynomial::new()));
+        assert_eq!("-1".parse::<Polynomial<GF4>>(), Ok(Polynomial::new_from_coefficients(vec![GF4::One])));
+    }
+
+    #[test]
+    fn test_display_from_str_roundtrip() {
+        let polys = [
+            Polynomial::new_from_coefficients(vec![GF4::Alpha, GF4::AlphaPlusOne, GF4::Zero, GF4::One]),
+            Polynomial::new_from_coefficients(vec![GF4::AlphaPlusOne]),
+            Polynomial::new_from_coefficients(vec![GF4::Zero, GF4::Zero, GF4::Zero, GF4::Zero, GF4::Zero, GF4::Zero, GF4::Zero, GF4::Zero, GF4::Zero, GF4::Zero, GF4::Zero, GF4::AlphaPlusOne]),
+            Polynomial::new(),
+        ];
+        for p in polys.iter() {
+            assert_eq!(&format!("{}", p).parse::<Polynomial<GF4>>().unwrap(), p);
+            assert_eq!(&format!("{:#}", p).parse::<Polynomial<GF4>>().unwrap(), p);
+        }
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        let err = "a*x^2 + b*x".parse::<Polynomial<GF4>>().unwrap_err();
+        assert_eq!(err, ParsePolynomialError {
+            position: 8,
+            kind: ParsePolynomialErrorKind::InvalidCoefficient("b".to_string()),
+        });
+        assert_eq!(format!("{}", err), "invalid coefficient 'b' at position 8");
+
+        let err = "x^2 + ω*x^q".parse::<Polynomial<GF4>>().unwrap_err();
+        assert_eq!(err.position, 10);
+        assert_eq!(err.kind, ParsePolynomialErrorKind::InvalidExponent("q".to_string()));
+
+        let err = "(a+1*x".parse::<Polynomial<GF4>>().unwrap_err();
+        assert_eq!(err.position, 0);
+        assert_eq!(err.kind, ParsePolynomialErrorKind::UnclosedParenthesis);
+
+        let err = "x^2 +".parse::<Polynomial<GF4>>().unwrap_err();
+        assert_eq!(err.position, 5);
+        assert_eq!(err.kind, ParsePolynomialErrorKind::UnexpectedEnd);
+
+        let err = "a*x x".parse::<Polynomial<GF4>>().unwrap_err();
+        assert_eq!(err.position, 4);
+        assert_eq!(err.kind, ParsePolynomialErrorKind::UnexpectedCharacter('x'));
+
+        let err = "a*1".parse::<Polynomial<GF4>>().unwrap_err();
+        assert_eq!(err.position, 2);
+
+        // exponents past MAX_PARSED_EXPONENT must fail instead of allocating
+        for exponent in ["18446744073709551615", "99999999999", "16777217"] {
+            let err = format!("x^{}", exponent).parse::<Polynomial<GF4>>().unwrap_err();
+            assert_eq!(err.position, 2);
+            assert_eq!(err.kind, ParsePolynomialErrorKind::InvalidExponent(exponent.to_string()));
+        }
+        let err = "a*x^99999999999 + 1".parse::<Polynomial<GF4>>().unwrap_err();
+        assert_eq!(err.position, 4);
+        assert_eq!(err.kind, ParsePolynomialErrorKind::InvalidExponent("99999999999".to_string()));
+        assert_eq!(format!("x^{}", MAX_PARSED_EXPONENT).parse::<Polynomial<GF4>>().unwrap().degree(), MAX_PARSED_EXPONENT);
+
+        assert!("".parse::<Polynomial<GF4>>().is_err());
+        assert!("(a+2)*x".parse::<Polynomial<GF4>>().is_err_and(|e| e.position == 1));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::galois_fields::GaloisField;
-use crate::polynomials::polynomial_operations::xgcd;
+use crate::polynomials::polynomial_operations::{divstep_invert, ext_gcd};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Polynomial<T: GaloisField>{
@@ -37,6 +37,13 @@ where T: GaloisField {
         }
     }
 
+    // The zero polynomial with room for `capacity` coefficients.
+    pub fn with_capacity(capacity: usize) -> Polynomial<T> {
+        let mut coefficients = Vec::with_capacity(capacity.max(1));
+        coefficients.push(T::generate_zero());
+        Polynomial { coefficients }
+    }
+
     pub fn new_from_coefficients(coefficients: Vec<T>) -> Polynomial<T> {
         if coefficients.is_empty() {
             Polynomial::new()
@@ -62,10 +69,34 @@ where T: GaloisField {
     }
 
     pub fn get_coefficient(&self, i: usize) -> Option<T> {
-        match self.coefficients.get(i) {
-            Some(val) => Some(val.clone()),
-            None => None
+        self.coefficients.get(i).cloned()
+    }
+
+    pub fn leading_coefficient(&self) -> T {
+        self.coefficients[self.coefficients.len() - 1].clone()
+    }
+
+    // Scales the polynomial so its leading coefficient is one; zero stays zero.
+    pub fn monic(&self) -> Polynomial<T> {
+        if self.is_zero() {
+            return self.clone();
+        }
+        let inv = T::generate_one().div(&self.leading_coefficient()).unwrap();
+        self.scale(&inv)
+    }
+
+    pub fn scale(&self, c: &T) -> Polynomial<T> {
+        Polynomial::new_from_coefficients(self.coefficients.iter().map(|item| item.mul(c)).collect())
+    }
+
+    // Multiplies by x^k.
+    pub fn shift(&self, k: usize) -> Polynomial<T> {
+        if self.is_zero() {
+            return self.clone();
         }
+        let mut coefficients = vec![T::generate_zero(); k];
+        coefficients.extend_from_slice(&self.coefficients);
+        Polynomial { coefficients }
     }
 
     pub fn add(&self, other: &Polynomial<T>) -> Polynomial<T> {
@@ -119,7 +150,7 @@ where T: GaloisField {
         } else {
             let mut current = self.coefficients.clone();
             let mut result = vec![T::generate_zero(); current.len()];
-            while current.len() - 1 >= other.degree() {
+            while current.len() > other.degree() && !(current.len() == 1 && current[0].is_zero()) {
                 let d = current[current.len() - 1]
                     .div(&other.coefficients[other.coefficients.len() - 1])
                     .unwrap();
@@ -135,8 +166,238 @@ where T: GaloisField {
     }
 
     pub fn invert(&self, modulus: &Polynomial<T>) -> Option<Polynomial<T>> {
-        let (_, maybe_inv) = xgcd(self, modulus);
-        maybe_inv
+        let (_, reduced) = self.div_mod(modulus)?;
+        let (gcd, s, _) = ext_gcd(&reduced, modulus);
+        if gcd.is_one() {
+            let (_, inv) = s.div_mod(modulus)?;
+            Some(inv)
+        } else {
+            None
+        }
+    }
+
+    pub fn derivative(&self) -> Polynomial<T> {
+        let p = T::characteristic();
+        let coefficients = self.coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, item)| {
+                // i * item, computed as (i mod p) repeated additions
+                let mut term = T::generate_zero();
+                for _ in 0..(i % p) {
+                    term = term.add(item);
+                }
+                term
+            })
+            .collect();
+        Polynomial::new_from_coefficients(coefficients)
+    }
+
+    // f(g(x)), evaluated with Horner's scheme.
+    pub fn compose(&self, other: &Polynomial<T>) -> Polynomial<T> {
+        let mut result = Polynomial::new();
+        for item in self.coefficients.iter().rev() {
+            result = result.mul(other).add(&Polynomial::new_from_coefficients(vec![item.clone()]));
+        }
+        result
+    }
+
+    pub fn pow(&self, exponent: usize) -> Polynomial<T> {
+        let mut result = Polynomial::new_from_coefficients(vec![T::generate_one()]);
+        let mut base = self.clone();
+        let mut e = exponent;
+        while e > 0 {
+            if e & 1 == 1 {
+                result = result.mul(&base);
+            }
+            base = base.mul(&base);
+            e >>= 1;
+        }
+        result
+    }
+
+    // self^exponent mod modulus; None if the modulus is zero.
+    pub fn pow_mod(&self, exponent: usize, modulus: &Polynomial<T>) -> Option<Polynomial<T>> {
+        let (_, mut base) = self.div_mod(modulus)?;
+        let (_, mut result) = Polynomial::new_from_coefficients(vec![T::generate_one()]).div_mod(modulus)?;
+        let mut e = exponent;
+        while e > 0 {
+            if e & 1 == 1 {
+                result = result.mul(&base).div_mod(modulus)?.1;
+            }
+            base = base.mul(&base).div_mod(modulus)?.1;
+            e >>= 1;
+        }
+        Some(result)
+    }
+
+    pub fn evaluate(&self, x: &T) -> T {
+        let mut result = T::generate_zero();
+        for item in self.coefficients.iter().rev() {
+            result = result.mul(x).add(item);
+        }
+        result
+    }
+
+    // Values at every field element, in the order given by T::elements().
+    pub fn evaluate_all(&self) -> Vec<T> {
+        T::elements().iter().map(|x| self.evaluate(x)).collect()
+    }
+
+    // Hamming weight and support of the polynomial as stored. They take no r
+    // and do not fold, so for an element of F[x]/(x^r - 1) call
+    // reduce_cyclic(r) first: x^5 + 1 has weight 2, but weight 0 mod x^5 - 1
+    // in characteristic 2.
+
+    pub fn weight(&self) -> usize {
+        self.coefficients.iter().filter(|item| !item.is_zero()).count()
+    }
+
+    // Positions of the non-zero coefficients in increasing order.
+    pub fn support(&self) -> Vec<usize> {
+        self.coefficients
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| !item.is_zero())
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    // The methods below treat a polynomial as an element of F[x]/(x^r - 1),
+    // i.e. as the length-r vector (c_0, ..., c_{r-1}) with the coefficients
+    // past the degree read as zeros. Inputs of degree >= r are first reduced
+    // by folding coefficient i onto position i mod r. r must be positive.
+
+    // Coefficient vector of exactly r entries.
+    pub fn to_padded(&self, r: usize) -> Vec<T> {
+        let mut padded = vec![T::generate_zero(); r];
+        for (i, item) in self.coefficients.iter().enumerate() {
+            padded[i % r] = padded[i % r].add(item);
+        }
+        padded
+    }
+
+    // Reduction modulo x^r - 1.
+    pub fn reduce_cyclic(&self, r: usize) -> Polynomial<T> {
+        Polynomial::new_from_coefficients(self.to_padded(r))
+    }
+
+    // x^k * self mod x^r - 1: coefficient i moves to position (i + k) mod r.
+    pub fn rotate(&self, k: usize, r: usize) -> Polynomial<T> {
+        let mut padded = self.to_padded(r);
+        padded.rotate_right(k % r);
+        Polynomial::new_from_coefficients(padded)
+    }
+
+    // self(x^-1) mod x^r - 1: coefficient i moves to position (r - i) mod r.
+    pub fn transpose(&self, r: usize) -> Polynomial<T> {
+        let mut padded = self.to_padded(r);
+        padded[1..].reverse();
+        Polynomial::new_from_coefficients(padded)
+    }
+
+    // Fixed iteration count; not audited for constant-time execution, see
+    // divstep_invert.
+    pub fn invert_constant_time(&self, modulus: &Polynomial<T>) -> Option<Polynomial<T>> {
+        divstep_invert(self, modulus)
+    }
+
+    // In-place and output-buffer variants of the arithmetic above. They reuse
+    // the capacity of the vectors they are given and only allocate when a
+    // result is longer than any buffer seen so far.
+
+    pub fn set_zero(&mut self) {
+        self.coefficients.clear();
+        self.coefficients.push(T::generate_zero());
+    }
+
+    pub fn set_one(&mut self) {
+        self.coefficients.clear();
+        self.coefficients.push(T::generate_one());
+    }
+
+    pub fn assign(&mut self, other: &Polynomial<T>) {
+        self.coefficients.clear();
+        self.coefficients.extend_from_slice(&other.coefficients);
+    }
+
+    pub fn add_assign(&mut self, other: &Polynomial<T>) {
+        if self.coefficients.len() < other.coefficients.len() {
+            self.coefficients.resize(other.coefficients.len(), T::generate_zero());
+        }
+        for (i, item) in other.coefficients.iter().enumerate() {
+            self.coefficients[i] = self.coefficients[i].add(item);
+        }
+        self.shrink_to_degree();
+    }
+
+    pub fn sub_assign(&mut self, other: &Polynomial<T>) {
+        if self.coefficients.len() < other.coefficients.len() {
+            self.coefficients.resize(other.coefficients.len(), T::generate_zero());
+        }
+        for (i, item) in other.coefficients.iter().enumerate() {
+            self.coefficients[i] = self.coefficients[i].sub(item);
+        }
+        self.shrink_to_degree();
+    }
+
+    pub fn scale_assign(&mut self, c: &T) {
+        for item in self.coefficients.iter_mut() {
+            *item = item.mul(c);
+        }
+        self.shrink_to_degree();
+    }
+
+    // out = a * b. The product is accumulated in scratch, whose buffer is then
+    // swapped with the one of out, so both keep their capacity for the next call.
+    pub fn mul_into(a: &Polynomial<T>, b: &Polynomial<T>, out: &mut Polynomial<T>, scratch: &mut Vec<T>) {
+        scratch.clear();
+        scratch.resize(a.degree() + b.degree() + 1, T::generate_zero());
+        for (i, item) in a.coefficients.iter().enumerate() {
+            if item.is_zero() {
+                continue;
+            }
+            for (j, jtem) in b.coefficients.iter().enumerate() {
+                scratch[i + j] = scratch[i + j].add(&item.mul(jtem));
+            }
+        }
+        std::mem::swap(&mut out.coefficients, scratch);
+        out.shrink_to_degree();
+    }
+
+    // Writes the quotient and remainder of self / other into the given buffers;
+    // returns false (leaving them untouched) if other is zero.
+    pub fn div_mod_into(&self, other: &Polynomial<T>, quotient: &mut Polynomial<T>, remainder: &mut Polynomial<T>) -> bool {
+        if other.is_zero() {
+            return false;
+        }
+        remainder.assign(self);
+        quotient.set_zero();
+        if self.degree() < other.degree() {
+            return true;
+        }
+        quotient.coefficients.resize(self.degree() - other.degree() + 1, T::generate_zero());
+        let lead_inv = T::generate_one().div(&other.leading_coefficient()).unwrap();
+        let current = &mut remainder.coefficients;
+        while current.len() > other.degree() && !(current.len() == 1 && current[0].is_zero()) {
+            let d = current[current.len() - 1].mul(&lead_inv);
+            let offset = current.len() - other.coefficients.len();
+            for (i, item) in other.coefficients.iter().enumerate() {
+                current[i + offset] = current[i + offset].sub(&item.mul(&d));
+            }
+            quotient.coefficients[offset] = d;
+            remove_trailing_zeros(current);
+        }
+        quotient.shrink_to_degree();
+        true
+    }
+}
+
+impl<T> Default for Polynomial<T>
+where T: GaloisField {
+    fn default() -> Self {
+        Polynomial::new()
     }
 }
 
@@ -315,6 +576,220 @@ mod polynomial_tests {
         assert_eq!(p6_mod.coefficients, p3.coefficients);
     }
 
+    #[test]
+    fn test_polynomial_leading_coefficient_monic() {
+        let p = Polynomial::new_from_coefficients(vec![
+            GF4::One, GF4::Zero, GF4::AlphaPlusOne, GF4::Alpha
+        ]);
+        assert_eq!(p.leading_coefficient(), GF4::Alpha);
+        assert_eq!(p.monic().coefficients, vec![GF4::AlphaPlusOne, GF4::Zero, GF4::Alpha, GF4::One]);
+        assert!(Polynomial::<GF4>::new().monic().is_zero());
+        assert_eq!(p.scale(&GF4::Zero), Polynomial::new());
+    }
+
+    #[test]
+    fn test_polynomial_shift() {
+        let p = Polynomial::new_from_coefficients(vec![GF4::Alpha, GF4::One]);
+        assert_eq!(p.shift(0), p);
+        assert_eq!(p.shift(2).coefficients, vec![GF4::Zero, GF4::Zero, GF4::Alpha, GF4::One]);
+        assert!(Polynomial::<GF4>::new().shift(3).is_zero());
+    }
+
+    #[test]
+    fn test_polynomial_derivative() {
+        // (x^4 + a*x^3 + x^2 + (a+1)*x + 1)' = a*x^2 + (a+1) in characteristic 2
+        let p = Polynomial::new_from_coefficients(vec![
+            GF4::One, GF4::AlphaPlusOne, GF4::One, GF4::Alpha, GF4::One
+        ]);
+        assert_eq!(p.derivative().coefficients, vec![GF4::AlphaPlusOne, GF4::Zero, GF4::Alpha]);
+        assert!(Polynomial::new_from_coefficients(vec![GF4::Alpha]).derivative().is_zero());
+    }
+
+    #[test]
+    fn test_polynomial_compose() {
+        // f = x^2 + a, g = x + 1, f(g) = x^2 + 1 + a
+        let f = Polynomial::new_from_coefficients(vec![GF4::Alpha, GF4::Zero, GF4::One]);
+        let g = Polynomial::new_from_coefficients(vec![GF4::One, GF4::One]);
+        assert_eq!(f.compose(&g).coefficients, vec![GF4::AlphaPlusOne, GF4::Zero, GF4::One]);
+
+        let x = Polynomial::new_from_coefficients(vec![GF4::Zero, GF4::One]);
+        assert_eq!(f.compose(&x), f);
+        assert_eq!(f.compose(&g).evaluate(&GF4::Alpha), f.evaluate(&g.evaluate(&GF4::Alpha)));
+    }
+
+    #[test]
+    fn test_polynomial_pow() {
+        let p = Polynomial::new_from_coefficients(vec![GF4::Alpha, GF4::One]);
+        assert!(p.pow(0).is_one());
+        assert_eq!(p.pow(1), p);
+        assert_eq!(p.pow(3), p.mul(&p).mul(&p));
+        // squaring is additive in characteristic 2
+        assert_eq!(p.pow(2).coefficients, vec![GF4::AlphaPlusOne, GF4::Zero, GF4::One]);
+    }
+
+    #[test]
+    fn test_polynomial_pow_mod() {
+        // x^4 = x mod (x^4 - x), so x^(4^k) = x
+        let m = Polynomial::new_from_coefficients(vec![
+            GF4::Zero, GF4::One, GF4::Zero, GF4::Zero, GF4::One
+        ]);
+        let x = Polynomial::new_from_coefficients(vec![GF4::Zero, GF4::One]);
+        assert_eq!(x.pow_mod(16, &m), Some(x.clone()));
+        assert!(x.pow_mod(0, &m).unwrap().is_one());
+
+        let p = Polynomial::new_from_coefficients(vec![GF4::AlphaPlusOne, GF4::Alpha, GF4::One]);
+        assert_eq!(p.pow_mod(13, &m), Some(p.pow(13).div_mod(&m).unwrap().1));
+        assert!(p.pow_mod(2, &Polynomial::new()).is_none());
+    }
+
+    #[test]
+    fn test_polynomial_weight_support() {
+        let p = Polynomial::new_from_coefficients(vec![
+            GF4::Zero, GF4::One, GF4::Zero, GF4::Alpha, GF4::AlphaPlusOne, GF4::Zero
+        ]);
+        assert_eq!(p.weight(), 3);
+        assert_eq!(p.support(), vec![1, 3, 4]);
+        assert_eq!(Polynomial::<GF4>::new().weight(), 0);
+        assert!(Polynomial::<GF4>::new().support().is_empty());
+
+        // no folding modulo x^r - 1 without an explicit reduce_cyclic
+        let q = Polynomial::new_from_coefficients(vec![
+            GF4::One, GF4::Zero, GF4::Zero, GF4::Zero, GF4::Zero, GF4::One
+        ]);
+        assert_eq!(q.weight(), 2);
+        assert_eq!(q.support(), vec![0, 5]);
+        assert_eq!(q.reduce_cyclic(5).weight(), 0);
+    }
+
+    #[test]
+    fn test_polynomial_to_padded() {
+        let p = Polynomial::new_from_coefficients(vec![GF4::One, GF4::Alpha]);
+        assert_eq!(p.to_padded(4), vec![GF4::One, GF4::Alpha, GF4::Zero, GF4::Zero]);
+        assert_eq!(Polynomial::<GF4>::new().to_padded(2), vec![GF4::Zero, GF4::Zero]);
+
+        // x^5 + a*x + 1 = a*x + 1 + x^2 mod x^3 - 1
+        let p = Polynomial::new_from_coefficients(vec![
+            GF4::One, GF4::Alpha, GF4::Zero, GF4::Zero, GF4::Zero, GF4::One
+        ]);
+        assert_eq!(p.to_padded(3), vec![GF4::One, GF4::Alpha, GF4::One]);
+        assert_eq!(p.reduce_cyclic(3).coefficients, vec![GF4::One, GF4::Alpha, GF4::One]);
+        assert_eq!(p.reduce_cyclic(5).coefficients, vec![GF4::Zero, GF4::Alpha]);
+    }
+
+    #[test]
+    fn test_polynomial_rotate() {
+        let r = 5;
+        let p = Polynomial::new_from_coefficients(vec![GF4::One, GF4::Zero, GF4::Zero, GF4::Alpha]);
+        assert_eq!(p.rotate(0, r), p);
+        assert_eq!(p.rotate(1, r).coefficients, vec![GF4::Zero, GF4::One, GF4::Zero, GF4::Zero, GF4::Alpha]);
+        assert_eq!(p.rotate(2, r).coefficients, vec![GF4::Alpha, GF4::Zero, GF4::One]);
+        assert_eq!(p.rotate(7, r), p.rotate(2, r));
+
+        let mut x_k = vec![GF4::Zero; 4];
+        x_k[3] = GF4::One;
+        let x_k = Polynomial::new_from_coefficients(x_k);
+        assert_eq!(p.rotate(3, r), p.mul(&x_k).reduce_cyclic(r));
+    }
+
+    #[test]
+    fn test_polynomial_transpose() {
+        let r = 5;
+        let p = Polynomial::new_from_coefficients(vec![GF4::Alpha, GF4::One, GF4::Zero, GF4::AlphaPlusOne]);
+        let t = p.transpose(r);
+        assert_eq!(t.coefficients, vec![GF4::Alpha, GF4::Zero, GF4::AlphaPlusOne, GF4::Zero, GF4::One]);
+        assert_eq!(t.transpose(r), p);
+        assert_eq!(t.weight(), p.weight());
+
+        // transposition is a ring automorphism of F[x]/(x^r - 1)
+        let q = Polynomial::new_from_coefficients(vec![GF4::One, GF4::AlphaPlusOne, GF4::Alpha]);
+        assert_eq!(p.mul(&q).transpose(r), p.transpose(r).mul(&q.transpose(r)).reduce_cyclic(r));
+    }
+
+    #[test]
+    fn test_polynomial_add_sub_assign() {
+        let p1 = Polynomial::new_from_coefficients(vec![
+            GF4::Zero, GF4::One, GF4::Alpha, GF4::AlphaPlusOne
+        ]);
+        let p2 = Polynomial::new_from_coefficients(vec![GF4::One, GF4::One]);
+
+        let mut p = p2.clone();
+        p.add_assign(&p1);
+        assert_eq!(p, p1.add(&p2));
+        p.sub_assign(&p1);
+        assert_eq!(p, p2);
+        p.sub_assign(&p2);
+        assert!(p.is_zero());
+        assert_eq!(p.coefficients.len(), 1);
+
+        let mut p = p1.clone();
+        p.scale_assign(&GF4::Alpha);
+        assert_eq!(p, p1.scale(&GF4::Alpha));
+        p.assign(&p2);
+        assert_eq!(p, p2);
+        p.set_one();
+        assert!(p.is_one());
+        p.set_zero();
+        assert!(p.is_zero());
+    }
+
+    #[test]
+    fn test_polynomial_mul_into() {
+        let p1 = Polynomial::new_from_coefficients(vec![
+            GF4::Zero, GF4::One, GF4::Alpha, GF4::Alpha, GF4::AlphaPlusOne
+        ]);
+        let p2 = Polynomial::new_from_coefficients(vec![
+            GF4::AlphaPlusOne, GF4::Zero, GF4::One
+        ]);
+        let mut out = Polynomial::new();
+        let mut scratch = Vec::new();
+        Polynomial::mul_into(&p1, &p2, &mut out, &mut scratch);
+        assert_eq!(out, p1.mul(&p2));
+        Polynomial::mul_into(&p2, &Polynomial::new(), &mut out, &mut scratch);
+        assert!(out.is_zero());
+
+        // once both buffers are large enough, no further allocation happens
+        Polynomial::mul_into(&p1, &p2, &mut out, &mut scratch);
+        let capacities = (out.coefficients.capacity(), scratch.capacity());
+        for _ in 0..10 {
+            Polynomial::mul_into(&p1, &p2, &mut out, &mut scratch);
+        }
+        assert_eq!(capacities, (out.coefficients.capacity(), scratch.capacity()));
+    }
+
+    #[test]
+    fn test_polynomial_div_mod_into() {
+        let p2 = Polynomial::new_from_coefficients(vec![
+            GF4::One, GF4::Zero, GF4::One, GF4::Alpha, GF4::AlphaPlusOne
+        ]);
+        let p3 = Polynomial::new_from_coefficients(vec![GF4::One, GF4::Alpha]);
+        let mut q = Polynomial::new();
+        let mut r = Polynomial::new();
+
+        assert!(p2.div_mod_into(&p3, &mut q, &mut r));
+        assert_eq!((q.clone(), r.clone()), p2.div_mod(&p3).unwrap());
+        assert!(p3.div_mod_into(&p2, &mut q, &mut r));
+        assert_eq!((q.clone(), r.clone()), p3.div_mod(&p2).unwrap());
+        assert!(!p2.div_mod_into(&Polynomial::new(), &mut q, &mut r));
+
+        let c = Polynomial::new_from_coefficients(vec![GF4::Alpha]);
+        assert!(p2.div_mod_into(&c, &mut q, &mut r));
+        assert_eq!((q, r), p2.div_mod(&c).unwrap());
+    }
+
+    #[test]
+    fn test_polynomial_evaluate() {
+        // x^3 + a*x + (a+1)
+        let p = Polynomial::new_from_coefficients(vec![
+            GF4::AlphaPlusOne, GF4::Alpha, GF4::Zero, GF4::One
+        ]);
+        assert_eq!(p.evaluate(&GF4::Zero), GF4::AlphaPlusOne);
+        assert_eq!(p.evaluate(&GF4::One), GF4::Zero);
+        assert_eq!(p.evaluate(&GF4::Alpha), GF4::One);
+        assert_eq!(p.evaluate(&GF4::AlphaPlusOne), GF4::AlphaPlusOne);
+        assert_eq!(p.evaluate_all(), vec![GF4::AlphaPlusOne, GF4::Zero, GF4::One, GF4::AlphaPlusOne]);
+        assert_eq!(Polynomial::<GF4>::new().evaluate(&GF4::Alpha), GF4::Zero);
+    }
+
     #[test]
     fn test_polynomial_invert() {
         {
@@ -327,6 +802,7 @@ mod polynomial_tests {
             let inv = p.invert(&m);
             assert!(inv.is_some());
             assert_eq!(inv.unwrap().coefficients, vec![GF4::One, GF4::Zero, GF4::AlphaPlusOne]);
+            assert_eq!(p.invert_constant_time(&m), p.invert(&m));
         }
         {
             let p = Polynomial::new_from_coefficients(vec![
@@ -337,6 +813,28 @@ mod polynomial_tests {
             ]);
             let inv = p.invert(&m);
             assert!(inv.is_none());
+            assert!(p.invert_constant_time(&m).is_none());
+        }
+        {
+            // the modulus may have lower degree than the polynomial,
+            // and the gcd may be a constant other than one
+            let p = Polynomial::new_from_coefficients(vec![
+                GF4::One, GF4::Zero, GF4::Zero, GF4::Zero, GF4::Zero, GF4::One
+            ]);
+            let m = Polynomial::new_from_coefficients(vec![GF4::Alpha, GF4::One]);
+            let inv = m.invert(&p);
+            assert!(inv.is_some());
+            assert!(inv.unwrap().mul(&m).div_mod(&p).unwrap().1.is_one());
+
+            let inv = p.invert(&m);
+            assert!(inv.is_some());
+            assert!(inv.unwrap().mul(&p).div_mod(&m).unwrap().1.is_one());
+        }
+        {
+            let p = Polynomial::new_from_coefficients(vec![GF4::Alpha]);
+            let m = Polynomial::new_from_coefficients(vec![GF4::One, GF4::One, GF4::One]);
+            assert_eq!(p.invert(&m).unwrap().coefficients, vec![GF4::AlphaPlusOne]);
+            assert!(p.invert(&Polynomial::new()).is_none());
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,282 @@
+use crate::galois_fields::GaloisField;
+use crate::polynomials::polynomial::Polynomial;
+
+// Matrix whose entries are elements of F[x]/(x^r - 1), i.e. the block-circulant
+// matrices of quasi-cyclic codes of index n0 with circulant size r. Entries are
+// always kept reduced modulo x^r - 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolynomialMatrix<T: GaloisField> {
+    rows: usize,
+    cols: usize,
+    r: usize,
+    entries: Vec<Polynomial<T>>,
+}
+
+fn negate<T: GaloisField>(p: &Polynomial<T>) -> Polynomial<T> {
+    Polynomial::new().sub(p)
+}
+
+impl<T> PolynomialMatrix<T>
+where T: GaloisField {
+
+    pub fn new(rows: usize, cols: usize, r: usize) -> PolynomialMatrix<T> {
+        PolynomialMatrix {
+            rows,
+            cols,
+            r,
+            entries: vec![Polynomial::new(); rows * cols],
+        }
+    }
+
+    pub fn identity(n: usize, r: usize) -> PolynomialMatrix<T> {
+        let mut m = PolynomialMatrix::new(n, n, r);
+        for i in 0..n {
+            m.entries[i * n + i] = Polynomial::new_from_coefficients(vec![T::generate_one()]);
+        }
+        m
+    }
+
+    // None if the rows differ in length.
+    pub fn new_from_rows(rows: Vec<Vec<Polynomial<T>>>, r: usize) -> Option<PolynomialMatrix<T>> {
+        let cols = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != cols) {
+            return None;
+        }
+        Some(PolynomialMatrix {
+            rows: rows.len(),
+            cols,
+            r,
+            entries: rows.into_iter().flatten().map(|p| p.reduce_cyclic(r)).collect(),
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn block_size(&self) -> usize {
+        self.r
+    }
+
+    // x^r - 1
+    pub fn modulus(&self) -> Polynomial<T> {
+        let mut coefficients = vec![T::generate_zero(); self.r + 1];
+        coefficients[0] = T::generate_zero().sub(&T::generate_one());
+        coefficients[self.r] = T::generate_one();
+        Polynomial::new_from_coefficients(coefficients)
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&Polynomial<T>> {
+        if i < self.rows && j < self.cols {
+            Some(&self.entries[i * self.cols + j])
+        } else {
+            None
+        }
+    }
+
+    // Returns false if (i, j) is out of bounds.
+    pub fn set(&mut self, i: usize, j: usize, value: &Polynomial<T>) -> bool {
+        if i < self.rows && j < self.cols {
+            self.entries[i * self.cols + j] = value.reduce_cyclic(self.r);
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn add(&self, other: &PolynomialMatrix<T>) -> Option<PolynomialMatrix<T>> {
+        if self.rows != other.rows || self.cols != other.cols || self.r != other.r {
+            return None;
+        }
+        Some(PolynomialMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            r: self.r,
+            entries: self.entries.iter().zip(other.entries.iter()).map(|(a, b)| a.add(b)).collect(),
+        })
+    }
+
+    // None if the inner dimensions or the block sizes differ.
+    pub fn mul(&self, other: &PolynomialMatrix<T>) -> Option<PolynomialMatrix<T>> {
+        if self.cols != other.rows || self.r != other.r {
+            return None;
+        }
+        let mut product = PolynomialMatrix::new(self.rows, other.cols, self.r);
+        for i in 0..self.rows {
+            for j in 0..other.cols {
+                let mut sum = Polynomial::new();
+                for k in 0..self.cols {
+                    sum = sum.add(&self.entries[i * self.cols + k].mul(&other.entries[k * other.cols + j]));
+                }
+                product.entries[i * other.cols + j] = sum.reduce_cyclic(self.r);
+            }
+        }
+        Some(product)
+    }
+
+    fn minor(&self, row: usize, col: usize) -> PolynomialMatrix<T> {
+        let mut entries = Vec::with_capacity((self.rows - 1) * (self.cols - 1));
+        for i in (0..self.rows).filter(|i| *i != row) {
+            for j in (0..self.cols).filter(|j| *j != col) {
+                entries.push(self.entries[i * self.cols + j].clone());
+            }
+        }
+        PolynomialMatrix {
+            rows: self.rows - 1,
+            cols: self.cols - 1,
+            r: self.r,
+            entries,
+        }
+    }
+
+    // Cofactor expansion along the first row. Unlike Gaussian elimination it
+    // needs no divisions, so it works over the ring F[x]/(x^r - 1) with its
+    // zero divisors. None if the matrix is not square.
+    pub fn determinant(&self) -> Option<Polynomial<T>> {
+        if self.rows != self.cols {
+            return None;
+        }
+        match self.rows {
+            0 => Some(Polynomial::new_from_coefficients(vec![T::generate_one()])),
+            1 => Some(self.entries[0].clone()),
+            2 => Some(self.entries[0].mul(&self.entries[3]).sub(&self.entries[1].mul(&self.entries[2])).reduce_cyclic(self.r)),
+            n => {
+                let mut det = Polynomial::new();
+                for j in 0..n {
+                    if self.entries[j].is_zero() {
+                        continue;
+                    }
+                    let term = self.entries[j].mul(&self.minor(0, j).determinant()?);
+                    det = if j % 2 == 0 { det.add(&term) } else { det.sub(&term) };
+                }
+                Some(det.reduce_cyclic(self.r))
+            }
+        }
+    }
+
+    // adj(M) / det(M); None if the matrix is not square or its determinant
+    // is not a unit of F[x]/(x^r - 1).
+    pub fn invert(&self) -> Option<PolynomialMatrix<T>> {
+        let det_inv = self.determinant()?.invert(&self.modulus())?;
+        let n = self.rows;
+        let mut inverse = PolynomialMatrix::new(n, n, self.r);
+        if n == 1 {
+            inverse.entries[0] = det_inv;
+            return Some(inverse);
+        }
+        for i in 0..n {
+            for j in 0..n {
+                let cofactor = self.minor(j, i).determinant()?;
+                let cofactor = if (i + j) % 2 == 0 { cofactor } else { negate(&cofactor) };
+                inverse.entries[i * n + j] = cofactor.mul(&det_inv).reduce_cyclic(self.r);
+            }
+        }
+        Some(inverse)
+    }
+}
+
+#[cfg(test)]
+mod polynomial_matrix_tests {
+    use crate::galois_fields::gf4_number::GF4;
+    use rand_chacha::ChaCha20Rng;
+    use crate::random::context::Context;
+    use super::*;
+
+    fn p(s: &str) -> Polynomial<GF4> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_polynomial_matrix_new_from_rows() {
+        let m = PolynomialMatrix::new_from_rows(vec![
+            vec![p("x^5 + 1"), p("a*x")],
+            vec![p("x^2"), p("0")],
+        ], 5).unwrap();
+        // x^5 + 1 = 0 mod x^5 - 1 in characteristic 2
+        assert!(m.get(0, 0).unwrap().is_zero());
+        assert_eq!(m.get(0, 1), Some(&p("a*x")));
+        assert!(m.get(2, 0).is_none());
+        assert_eq!(m.block_size(), 5);
+        assert!(PolynomialMatrix::new_from_rows(vec![vec![p("1")], vec![]], 5).is_none());
+    }
+
+    #[test]
+    fn test_polynomial_matrix_mul() {
+        let r = 5;
+        let a = PolynomialMatrix::new_from_rows(vec![
+            vec![p("x^4 + 1"), p("a*x")],
+            vec![p("x^2"), p("x + a")],
+        ], r).unwrap();
+        let b = PolynomialMatrix::new_from_rows(vec![
+            vec![p("x^3"), p("1"), p("a")],
+            vec![p("x"), p("0"), p("x^4")],
+        ], r).unwrap();
+        let ab = a.mul(&b).unwrap();
+        assert_eq!(ab.rows(), 2);
+        assert_eq!(ab.cols(), 3);
+        // (x^4 + 1) * x^3 + a*x * x = x^7 + x^3 + a*x^2 = x^3 + (a+1)*x^2 mod x^5 - 1
+        assert_eq!(ab.get(0, 0), Some(&p("x^3 + (a+1)*x^2")));
+        assert_eq!(ab.get(1, 2), Some(&p("x^5 + a*x^4 + a*x^2").reduce_cyclic(r)));
+        assert_eq!(a.mul(&PolynomialMatrix::identity(2, r)), Some(a.clone()));
+        assert!(b.mul(&a).is_none());
+        assert!(a.mul(&PolynomialMatrix::identity(2, 7)).is_none());
+    }
+
+    #[test]
+    fn test_polynomial_matrix_determinant() {
+        let r = 5;
+        let m = PolynomialMatrix::new_from_rows(vec![
+            vec![p("x^4 + 1"), p("a*x")],
+            vec![p("x^2"), p("x + a")],
+        ], r).unwrap();
+        let expected = p("x^4 + 1").mul(&p("x + a")).sub(&p("a*x").mul(&p("x^2"))).reduce_cyclic(r);
+        assert_eq!(m.determinant(), Some(expected));
+        assert!(PolynomialMatrix::<GF4>::new(2, 3, r).determinant().is_none());
+        assert!(PolynomialMatrix::<GF4>::identity(4, r).determinant().unwrap().is_one());
+
+        // det(AB) = det(A) det(B)
+        let mut ctx = Context::from_seed([0x40; 32]);
+        let random = |ctx: &mut Context<ChaCha20Rng>| {
+            let rows = (0..3).map(|_| (0..3).map(|_| ctx.random_polynomial::<GF4>(r - 1)).collect()).collect();
+            PolynomialMatrix::new_from_rows(rows, r).unwrap()
+        };
+        let a = random(&mut ctx);
+        let b = random(&mut ctx);
+        let det_ab = a.mul(&b).unwrap().determinant().unwrap();
+        let det_a_det_b = a.determinant().unwrap().mul(&b.determinant().unwrap()).reduce_cyclic(r);
+        assert_eq!(det_ab, det_a_det_b);
+    }
+
+    #[test]
+    fn test_polynomial_matrix_invert() {
+        let r = 7;
+        let mut ctx = Context::from_seed([0x41; 32]);
+        for n in 1..=3 {
+            let mut inverted = 0;
+            for _ in 0..10 {
+                let rows = (0..n).map(|_| (0..n).map(|_| ctx.random_polynomial::<GF4>(r - 1)).collect()).collect();
+                let m = PolynomialMatrix::new_from_rows(rows, r).unwrap();
+                let det_is_unit = m.determinant().unwrap().invert(&m.modulus()).is_some();
+                match m.invert() {
+                    Some(inv) => {
+                        inverted += 1;
+                        assert!(det_is_unit);
+                        assert_eq!(m.mul(&inv), Some(PolynomialMatrix::identity(n, r)));
+                        assert_eq!(inv.mul(&m), Some(PolynomialMatrix::identity(n, r)));
+                    }
+                    None => assert!(!det_is_unit),
+                }
+            }
+            assert!(inverted > 0);
+        }
+
+        // rows (h, h) are linearly dependent
+        let h = p("x^3 + a*x + 1");
+        let singular = PolynomialMatrix::new_from_rows(vec![vec![h.clone(), h.clone()], vec![h.clone(), h]], r).unwrap();
+        assert!(singular.invert().is_none());
+    }
+}
@@ -1,6 +1,10 @@
 use crate::GaloisField;
 use crate::polynomials::polynomial::Polynomial;
 
+// Legacy inverse-oriented gcd: only accepts deg(poly) < deg(modulus) and
+// reports no inverse when the last non-zero remainder is a constant other
+// than one, even though poly is then invertible.
+#[deprecated(note = "use ext_gcd for the gcd and Bezout coefficients, or Polynomial::invert")]
 pub fn xgcd<T: GaloisField>(poly: &Polynomial<T>, modulus: &Polynomial<T>) -> (Option<Polynomial<T>>, Option<Polynomial<T>>) {
     if modulus.degree() <= poly.degree() || poly.is_zero() {
         (None, None)
@@ -8,7 +12,7 @@ pub fn xgcd<T: GaloisField>(poly: &Polynomial<T>, modulus: &Polynomial<T>) -> (O
         let mut r_last = modulus.clone();
         let mut r_current = poly.clone();
         let mut t_last = Polynomial::<T>::new();
-        let mut t_current = Polynomial::<T>::new_from_coefficients(vec![T::generate_zero()]);
+        let mut t_current = Polynomial::<T>::new_from_coefficients(vec![T::generate_one()]);
 
         loop {
             if let Some((q_current, mod_current)) = r_last.div_mod(&r_current) {
@@ -29,13 +33,256 @@ pub fn xgcd<T: GaloisField>(poly: &Polynomial<T>, modulus: &Polynomial<T>) -> (O
     }
 }
 
+// Extended Euclidean algorithm for arbitrary a and b. Returns (g, s, t) with
+// s*a + t*b = g, where g is the monic gcd (or zero if both inputs are zero).
+pub fn ext_gcd<T: GaloisField>(a: &Polynomial<T>, b: &Polynomial<T>) -> (Polynomial<T>, Polynomial<T>, Polynomial<T>) {
+    let one = Polynomial::new_from_coefficients(vec![T::generate_one()]);
+    let mut r_last = a.clone();
+    let mut r_current = b.clone();
+    let mut s_last = one.clone();
+    let mut s_current = Polynomial::<T>::new();
+    let mut t_last = Polynomial::<T>::new();
+    let mut t_current = one;
+
+    while !r_current.is_zero() {
+        let (q, r) = r_last.div_mod(&r_current).unwrap();
+        let s = s_last.sub(&q.mul(&s_current));
+        let t = t_last.sub(&q.mul(&t_current));
+        r_last = r_current;
+        r_current = r;
+        s_last = s_current;
+        s_current = s;
+        t_last = t_current;
+        t_current = t;
+    }
+
+    if r_last.is_zero() {
+        return (r_last, s_last, t_last);
+    }
+    let scale = T::generate_one().div(&r_last.leading_coefficient()).unwrap();
+    (r_last.scale(&scale), s_last.scale(&scale), t_last.scale(&scale))
+}
+
+pub fn gcd<T: GaloisField>(a: &Polynomial<T>, b: &Polynomial<T>) -> Polynomial<T> {
+    let (g, _, _) = ext_gcd(a, b);
+    g
+}
+
+// Monic least common multiple; zero if either input is zero.
+pub fn lcm<T: GaloisField>(a: &Polynomial<T>, b: &Polynomial<T>) -> Polynomial<T> {
+    if a.is_zero() || b.is_zero() {
+        return Polynomial::new();
+    }
+    let (product, _) = a.mul(b).div_mod(&gcd(a, b)).unwrap();
+    product.monic()
+}
+
+// Inversion modulo a fixed modulus using Bernstein-Yang divsteps, following
+// the structure of the NTRU Prime reference inversion. The iteration count and
+// the sequence of field operations depend only on deg(modulus), but the code
+// is not audited for constant-time execution: the swap decision tests g[0]
+// with is_zero and picks its multiplier from a table, and the arithmetic of a
+// GaloisField such as GF4 may itself be table lookups on secret coefficients.
+// The input is expected to be already reduced, deg(poly) < deg(modulus);
+// reducing a larger input falls back to the variable-time div_mod.
+pub fn divstep_invert<T: GaloisField>(poly: &Polynomial<T>, modulus: &Polynomial<T>) -> Option<Polynomial<T>> {
+    let d = modulus.degree();
+    if d == 0 {
+        return None;
+    }
+    let reduced = if poly.degree() >= d {
+        poly.div_mod(modulus)?.1
+    } else {
+        poly.clone()
+    };
+
+    let zero = T::generate_zero();
+    let selector = [T::generate_zero(), T::generate_one()];
+
+    // f and g hold the reversed modulus and the reversed input
+    let mut f: Vec<T> = (0..=d).map(|i| modulus.get_coefficient(d - i).unwrap()).collect();
+    let mut g: Vec<T> = (0..=d)
+        .map(|i| if i < d { reduced.get_coefficient(d - 1 - i).unwrap_or(zero.clone()) } else { zero.clone() })
+        .collect();
+    let mut v: Vec<T> = vec![zero.clone(); d + 1];
+    let mut r: Vec<T> = vec![zero.clone(); d + 1];
+    r[0] = T::generate_one();
+    let mut delta: isize = 1;
+
+    for _ in 0..(2 * d - 1) {
+        v.rotate_right(1);
+        v[0] = zero.clone();
+
+        let delta_positive = ((-delta) as usize) >> (usize::BITS - 1);
+        let g_nonzero = !g[0].is_zero() as usize;
+        let swap = delta_positive & g_nonzero;
+        let mask = -(swap as isize);
+        delta ^= mask & (delta ^ -delta);
+        delta += 1;
+
+        let s = &selector[swap];
+        for i in 0..=d {
+            let t = s.mul(&f[i].sub(&g[i]));
+            f[i] = f[i].sub(&t);
+            g[i] = g[i].add(&t);
+            let t = s.mul(&v[i].sub(&r[i]));
+            v[i] = v[i].sub(&t);
+            r[i] = r[i].add(&t);
+        }
+
+        let f0 = f[0].clone();
+        let g0 = g[0].clone();
+        for i in 0..=d {
+            g[i] = f0.mul(&g[i]).sub(&g0.mul(&f[i]));
+            r[i] = f0.mul(&r[i]).sub(&g0.mul(&v[i]));
+        }
+
+        g.rotate_left(1);
+        g[d] = zero.clone();
+    }
+
+    let scale = T::generate_one().div(&f[0]).unwrap();
+    let coefficients: Vec<T> = (0..d).map(|i| v[d - 1 - i].mul(&scale)).collect();
+    if delta == 0 {
+        Some(Polynomial::new_from_coefficients(coefficients))
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod polynomial_operations_tests {
     use crate::galois_fields::gf4_number::GF4;
     use super::*;
 
 
+    fn all_gf4_polynomials(max_degree: usize) -> Vec<Polynomial<GF4>> {
+        let count = 4usize.pow(max_degree as u32 + 1);
+        (0..count).map(|mut n| {
+            let mut coefficients = Vec::new();
+            for _ in 0..=max_degree {
+                coefficients.push(GF4::from_number((n % 4) as u8).unwrap());
+                n /= 4;
+            }
+            Polynomial::new_from_coefficients(coefficients)
+        }).collect()
+    }
+
+    #[test]
+    fn test_ext_gcd() {
+        // gcd(x^3 + x^2 + x, a*x + 1) = x + (a+1), which is not monic in the input
+        let a = Polynomial::new_from_coefficients(vec![
+            GF4::Zero, GF4::One, GF4::One, GF4::One
+        ]);
+        let b = Polynomial::new_from_coefficients(vec![
+            GF4::One, GF4::Alpha
+        ]);
+        let (g, s, t) = ext_gcd(&a, &b);
+        assert_eq!(g, Polynomial::new_from_coefficients(vec![GF4::AlphaPlusOne, GF4::One]));
+        assert_eq!(s.mul(&a).add(&t.mul(&b)), g);
+
+        // coprime inputs whose remainder sequence ends in a constant other than one
+        let a = Polynomial::new_from_coefficients(vec![
+            GF4::One, GF4::Zero, GF4::Zero, GF4::Zero, GF4::Zero, GF4::One
+        ]);
+        let b = Polynomial::new_from_coefficients(vec![GF4::Alpha, GF4::One]);
+        let (g, s, t) = ext_gcd(&a, &b);
+        assert!(g.is_one());
+        assert_eq!(s.mul(&a).add(&t.mul(&b)), g);
+
+        // works regardless of argument order and degrees
+        let (g, s, t) = ext_gcd(&b, &a);
+        assert!(g.is_one());
+        assert_eq!(s.mul(&b).add(&t.mul(&a)), g);
+
+        let zero = Polynomial::<GF4>::new();
+        let (g, _, _) = ext_gcd(&zero, &zero);
+        assert!(g.is_zero());
+        let (g, s, t) = ext_gcd(&zero, &b);
+        assert_eq!(g, b);
+        assert_eq!(s.mul(&zero).add(&t.mul(&b)), g);
+    }
+
+    #[test]
+    fn test_ext_gcd_exhaustive() {
+        let polys = all_gf4_polynomials(2);
+        for a in polys.iter() {
+            for b in polys.iter() {
+                let (g, s, t) = ext_gcd(a, b);
+                assert_eq!(s.mul(a).add(&t.mul(b)), g);
+                if a.is_zero() && b.is_zero() {
+                    assert!(g.is_zero());
+                    continue;
+                }
+                assert!(g.leading_coefficient().is_one());
+                assert!(a.div_mod(&g).unwrap().1.is_zero());
+                assert!(b.div_mod(&g).unwrap().1.is_zero());
+            }
+        }
+    }
+
+    #[test]
+    fn test_gcd_lcm() {
+        // (x + 1)(x + a) and (x + 1)(x + a + 1)
+        let x1 = Polynomial::new_from_coefficients(vec![GF4::One, GF4::One]);
+        let xa = Polynomial::new_from_coefficients(vec![GF4::Alpha, GF4::One]);
+        let xb = Polynomial::new_from_coefficients(vec![GF4::AlphaPlusOne, GF4::One]);
+        let a = x1.mul(&xa);
+        let b = x1.mul(&xb).mul(&Polynomial::new_from_coefficients(vec![GF4::Alpha]));
+
+        assert_eq!(gcd(&a, &b), x1);
+        assert_eq!(lcm(&a, &b), x1.mul(&xa).mul(&xb));
+        assert!(lcm(&a, &Polynomial::new()).is_zero());
+        assert_eq!(gcd(&a, &Polynomial::new()), a);
+    }
+
+    #[test]
+    fn test_divstep_invert() {
+        let modulus = Polynomial::new_from_coefficients(vec![
+            GF4::Zero, GF4::One, GF4::Zero, GF4::Alpha
+        ]);
+        let poly = Polynomial::new_from_coefficients(vec![
+            GF4::One, GF4::Zero, GF4::One
+        ]);
+        let inv = divstep_invert(&poly, &modulus);
+        assert_eq!(inv, Some(Polynomial::new_from_coefficients(vec![
+            GF4::One, GF4::Zero, GF4::AlphaPlusOne
+        ])));
+
+        let modulus = Polynomial::new_from_coefficients(vec![
+            GF4::Zero, GF4::One, GF4::One, GF4::One
+        ]);
+        let poly = Polynomial::new_from_coefficients(vec![
+            GF4::One, GF4::Alpha
+        ]);
+        assert!(divstep_invert(&poly, &modulus).is_none());
+        assert!(divstep_invert(&Polynomial::new(), &modulus).is_none());
+    }
+
+    #[test]
+    fn test_divstep_invert_matches_invert() {
+        // x^5 - 1 and a modulus with zero constant term
+        let moduli = [
+            Polynomial::new_from_coefficients(vec![
+                GF4::One, GF4::Zero, GF4::Zero, GF4::Zero, GF4::Zero, GF4::One
+            ]),
+            Polynomial::new_from_coefficients(vec![
+                GF4::Zero, GF4::Alpha, GF4::One, GF4::Zero, GF4::AlphaPlusOne
+            ]),
+        ];
+        for modulus in moduli.iter() {
+            for poly in all_gf4_polynomials(modulus.degree() - 1) {
+                let inv = divstep_invert(&poly, modulus);
+                assert_eq!(inv, poly.invert(modulus), "{:?}", poly);
+                if let Some(inv) = inv {
+                    assert!(inv.mul(&poly).div_mod(modulus).unwrap().1.is_one());
+                }
+            }
+        }
+    }
+
     #[test]
+    #[allow(deprecated)]
     fn test_xgcd() {
         {
             let modulus = Polynomial::new_from_coefficients(vec![
@@ -0,0 +1,231 @@
+use crate::galois_fields::GaloisField;
+use crate::polynomials::polynomial::Polynomial;
+
+fn minus_one<T: GaloisField>() -> T {
+    T::generate_zero().sub(&T::generate_one())
+}
+
+fn sign<T: GaloisField>(negative: bool) -> T {
+    if negative { minus_one() } else { T::generate_one() }
+}
+
+// x^e for a possibly negative exponent; x must be non-zero when e < 0.
+fn pow_signed<T: GaloisField>(x: &T, e: isize) -> T {
+    if e >= 0 {
+        x.pow(e as usize)
+    } else {
+        T::generate_one().div(&x.pow(e.unsigned_abs())).unwrap()
+    }
+}
+
+// Resultant via the Euclidean algorithm, using
+//   res(a, b) = (-1)^(deg a * deg b) * lc(b)^(deg a - deg r) * res(b, r)
+// for r = a mod b, and res(a, c) = c^(deg a) for a constant c.
+// Zero if either argument is zero.
+pub fn resultant<T: GaloisField>(a: &Polynomial<T>, b: &Polynomial<T>) -> T {
+    let mut a = a.clone();
+    let mut b = b.clone();
+    let mut result = T::generate_one();
+    loop {
+        if a.is_zero() || b.is_zero() {
+            return T::generate_zero();
+        }
+        let m = a.degree();
+        let n = b.degree();
+        if n == 0 {
+            return result.mul(&b.leading_coefficient().pow(m));
+        }
+        if m == 0 {
+            return result.mul(&a.leading_coefficient().pow(n));
+        }
+        let (_, r) = a.div_mod(&b).unwrap();
+        if r.is_zero() {
+            return T::generate_zero();
+        }
+        result = result
+            .mul(&sign((m * n) % 2 == 1))
+            .mul(&b.leading_coefficient().pow(m - r.degree()));
+        a = b;
+        b = r;
+    }
+}
+
+// Brown-Collins subresultant algorithm (Cohen, Algorithm 3.3.7) specialised to
+// a field. Returns the polynomial remainder sequence, starting with the input
+// of higher degree, together with the resultant res(a, b).
+fn subresultant<T: GaloisField>(a: &Polynomial<T>, b: &Polynomial<T>) -> (Vec<Polynomial<T>>, T) {
+    let (mut a, mut b, mut s) = if a.degree() < b.degree() {
+        (b.clone(), a.clone(), sign::<T>(a.degree() % 2 == 1 && b.degree() % 2 == 1))
+    } else {
+        (a.clone(), b.clone(), T::generate_one())
+    };
+    let mut sequence = vec![a.clone(), b.clone()];
+    if a.is_zero() || b.is_zero() {
+        sequence.retain(|p| !p.is_zero());
+        return (sequence, T::generate_zero());
+    }
+    if b.degree() == 0 {
+        let res = s.mul(&b.leading_coefficient().pow(a.degree()));
+        return (sequence, res);
+    }
+
+    let mut g = T::generate_one();
+    let mut h = T::generate_one();
+    loop {
+        let delta = a.degree() - b.degree();
+        if a.degree() % 2 == 1 && b.degree() % 2 == 1 {
+            s = s.mul(&minus_one());
+        }
+        // pseudo-remainder lc(b)^(delta + 1) * a mod b
+        let (_, r) = a.div_mod(&b).unwrap();
+        let prem = r.scale(&b.leading_coefficient().pow(delta + 1));
+        a = b;
+        let divisor = g.mul(&h.pow(delta));
+        b = prem.scale(&T::generate_one().div(&divisor).unwrap());
+        g = a.leading_coefficient();
+        h = pow_signed(&h, 1 - delta as isize).mul(&g.pow(delta));
+        if b.is_zero() {
+            return (sequence, T::generate_zero());
+        }
+        sequence.push(b.clone());
+        if b.degree() == 0 {
+            break;
+        }
+    }
+    let d = a.degree() as isize;
+    h = pow_signed(&h, 1 - d).mul(&b.leading_coefficient().pow(d as usize));
+    (sequence, s.mul(&h))
+}
+
+// The subresultant polynomial remainder sequence of a and b, without zeros.
+pub fn subresultant_prs<T: GaloisField>(a: &Polynomial<T>, b: &Polynomial<T>) -> Vec<Polynomial<T>> {
+    subresultant(a, b).0
+}
+
+// The resultant as computed by the subresultant algorithm; agrees with resultant().
+pub fn subresultant_resultant<T: GaloisField>(a: &Polynomial<T>, b: &Polynomial<T>) -> T {
+    subresultant(a, b).1
+}
+
+// disc(f) = (-1)^(n(n-1)/2) * Res_{n,n-1}(f, f') / lc(f) with n = deg f, where
+// f' is read as a polynomial of formal degree n - 1 even if its actual degree
+// is lower, as happens in positive characteristic.
+pub fn discriminant<T: GaloisField>(poly: &Polynomial<T>) -> T {
+    let n = poly.degree();
+    if n == 0 {
+        return T::generate_zero();
+    }
+    let derivative = poly.derivative();
+    if derivative.is_zero() {
+        return T::generate_zero();
+    }
+    let lc = poly.leading_coefficient();
+    let res = resultant(poly, &derivative).mul(&lc.pow(n - 1 - derivative.degree()));
+    sign::<T>((n * (n - 1) / 2) % 2 == 1)
+        .mul(&res)
+        .div(&lc)
+        .unwrap()
+}
+
+#[cfg(test)]
+mod resultant_tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use crate::galois_fields::gf4_number::GF4;
+    use crate::polynomials::polynomial_operations::gcd;
+    use super::*;
+
+    fn random_polynomial(rng: &mut StdRng, max_degree: usize) -> Polynomial<GF4> {
+        Polynomial::new_from_coefficients((0..=max_degree).map(|_| GF4::generate_random(rng)).collect())
+    }
+
+    #[test]
+    fn test_resultant() {
+        // res(x - c, g) = g(c)
+        let g: Polynomial<GF4> = "a*x^3 + x + (a+1)".parse().unwrap();
+        for c in GF4::elements() {
+            let linear = Polynomial::new_from_coefficients(vec![c.clone(), GF4::One]);
+            assert_eq!(resultant(&linear, &g), g.evaluate(&c));
+        }
+
+        // res(a, c) = c^deg(a) for a constant c
+        let c = Polynomial::new_from_coefficients(vec![GF4::Alpha]);
+        assert_eq!(resultant(&g, &c), GF4::One);
+        assert_eq!(resultant(&c, &g), GF4::One);
+
+        // common factor x + 1
+        let a: Polynomial<GF4> = "x^2 + 1".parse().unwrap();
+        let b: Polynomial<GF4> = "x^3 + a*x + (a+1)".parse().unwrap();
+        assert_eq!(resultant(&a, &b), GF4::Zero);
+        assert_eq!(resultant(&a, &Polynomial::new()), GF4::Zero);
+    }
+
+    #[test]
+    fn test_resultant_multiplicative() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..50 {
+            let a = random_polynomial(&mut rng, 3);
+            let b = random_polynomial(&mut rng, 4);
+            let c = random_polynomial(&mut rng, 5);
+            assert_eq!(resultant(&a.mul(&b), &c), resultant(&a, &c).mul(&resultant(&b, &c)));
+        }
+    }
+
+    #[test]
+    fn test_resultant_detects_common_factors() {
+        let mut rng = StdRng::seed_from_u64(8);
+        for _ in 0..200 {
+            let a = random_polynomial(&mut rng, 4);
+            let b = random_polynomial(&mut rng, 4);
+            if a.is_zero() || b.is_zero() || (a.degree() == 0 && b.degree() == 0) {
+                continue;
+            }
+            assert_eq!(resultant(&a, &b).is_zero(), !gcd(&a, &b).is_one(), "{} / {}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_subresultant_matches_euclid() {
+        let mut rng = StdRng::seed_from_u64(9);
+        for _ in 0..200 {
+            let a = random_polynomial(&mut rng, 6);
+            let b = random_polynomial(&mut rng, 5);
+            assert_eq!(subresultant_resultant(&a, &b), resultant(&a, &b), "{} / {}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_subresultant_prs() {
+        let a: Polynomial<GF4> = "x^5 + a*x^3 + x + 1".parse().unwrap();
+        let b: Polynomial<GF4> = "x^3 + (a+1)*x^2 + a".parse().unwrap();
+        let sequence = subresultant_prs(&a, &b);
+        assert_eq!(sequence[0], a);
+        assert_eq!(sequence[1], b);
+        for window in sequence.windows(2) {
+            assert!(window[1].degree() < window[0].degree());
+        }
+        // every element is a multiple of the gcd, and the last one is the gcd up to a constant
+        let g = gcd(&a, &b);
+        assert_eq!(sequence.last().unwrap().monic(), g);
+
+        let c: Polynomial<GF4> = "x + 1".parse().unwrap();
+        let sequence = subresultant_prs(&a.mul(&c), &b.mul(&c));
+        assert_eq!(sequence.last().unwrap().monic(), gcd(&a, &b).mul(&c));
+    }
+
+    #[test]
+    fn test_discriminant() {
+        // x^2 + b*x + c has discriminant b^2 - 4c = b^2 in characteristic 2
+        let f: Polynomial<GF4> = "x^2 + a*x + 1".parse().unwrap();
+        assert_eq!(discriminant(&f), GF4::AlphaPlusOne);
+        let f: Polynomial<GF4> = "(a+1)*x^2 + a*x + 1".parse().unwrap();
+        // scaling f by c scales the discriminant of a quadratic by c^2
+        assert_eq!(discriminant(&f), GF4::AlphaPlusOne.pow(2).mul(&discriminant(&f.monic())));
+
+        // zero exactly for polynomials with a repeated factor
+        let square: Polynomial<GF4> = "x + a".parse::<Polynomial<GF4>>().unwrap().pow(2).mul(&"x + 1".parse().unwrap());
+        assert_eq!(discriminant(&square), GF4::Zero);
+        assert_ne!(discriminant(&"x^5 + 1".parse::<Polynomial<GF4>>().unwrap()), GF4::Zero);
+        assert_eq!(discriminant(&"x^4 + 1".parse::<Polynomial<GF4>>().unwrap()), GF4::Zero);
+    }
+}
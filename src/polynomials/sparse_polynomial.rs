@@ -0,0 +1,148 @@
+use crate::galois_fields::GaloisField;
+use crate::polynomials::polynomial::Polynomial;
+
+// Polynomial stored as its non-zero terms (exponent, coefficient), sorted by
+// exponent. Meant for the low-weight keys and errors of MDPC codes, where the
+// weight is far below the degree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparsePolynomial<T: GaloisField> {
+    terms: Vec<(usize, T)>,
+}
+
+impl<T> SparsePolynomial<T>
+where T: GaloisField {
+
+    pub fn new() -> SparsePolynomial<T> {
+        SparsePolynomial {
+            terms: Vec::new(),
+        }
+    }
+
+    // Terms may come in any order; repeated exponents are summed and zero
+    // coefficients dropped.
+    pub fn new_from_terms(mut terms: Vec<(usize, T)>) -> SparsePolynomial<T> {
+        terms.sort_by_key(|(exponent, _)| *exponent);
+        let mut merged: Vec<(usize, T)> = Vec::with_capacity(terms.len());
+        for (exponent, coefficient) in terms {
+            match merged.last_mut() {
+                Some((last, sum)) if *last == exponent => *sum = sum.add(&coefficient),
+                _ => merged.push((exponent, coefficient)),
+            }
+        }
+        merged.retain(|(_, coefficient)| !coefficient.is_zero());
+        SparsePolynomial {
+            terms: merged,
+        }
+    }
+
+    pub fn from_polynomial(poly: &Polynomial<T>) -> SparsePolynomial<T> {
+        SparsePolynomial {
+            terms: poly.support().into_iter().map(|i| (i, poly.get_coefficient(i).unwrap())).collect(),
+        }
+    }
+
+    pub fn to_polynomial(&self) -> Polynomial<T> {
+        let mut coefficients = vec![T::generate_zero(); self.degree() + 1];
+        for (exponent, coefficient) in self.terms.iter() {
+            coefficients[*exponent] = coefficient.clone();
+        }
+        Polynomial::new_from_coefficients(coefficients)
+    }
+
+    pub fn terms(&self) -> &[(usize, T)] {
+        &self.terms
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    // Same convention as Polynomial: the zero polynomial has degree 0.
+    pub fn degree(&self) -> usize {
+        self.terms.last().map_or(0, |(exponent, _)| *exponent)
+    }
+
+    pub fn weight(&self) -> usize {
+        self.terms.len()
+    }
+
+    pub fn support(&self) -> Vec<usize> {
+        self.terms.iter().map(|(exponent, _)| *exponent).collect()
+    }
+
+    pub fn get_coefficient(&self, i: usize) -> T {
+        match self.terms.binary_search_by_key(&i, |(exponent, _)| *exponent) {
+            Ok(index) => self.terms[index].1.clone(),
+            Err(_) => T::generate_zero(),
+        }
+    }
+
+    // Product with a dense polynomial modulo x^r - 1 in O(weight * r).
+    pub fn mul_cyclic(&self, dense: &Polynomial<T>, r: usize) -> Polynomial<T> {
+        let dense = dense.to_padded(r);
+        let mut product = vec![T::generate_zero(); r];
+        for (exponent, coefficient) in self.terms.iter() {
+            let shift = exponent % r;
+            for (j, item) in dense.iter().enumerate() {
+                let k = (j + shift) % r;
+                product[k] = product[k].add(&coefficient.mul(item));
+            }
+        }
+        Polynomial::new_from_coefficients(product)
+    }
+}
+
+impl<T> Default for SparsePolynomial<T>
+where T: GaloisField {
+    fn default() -> Self {
+        SparsePolynomial::new()
+    }
+}
+
+#[cfg(test)]
+mod sparse_polynomial_tests {
+    use crate::galois_fields::gf4_number::GF4;
+    use crate::random::context::Context;
+    use super::*;
+
+    #[test]
+    fn test_sparse_polynomial_new_from_terms() {
+        let p = SparsePolynomial::new_from_terms(vec![
+            (7, GF4::Alpha),
+            (2, GF4::One),
+            (7, GF4::One),
+            (4, GF4::AlphaPlusOne),
+            (4, GF4::AlphaPlusOne),
+        ]);
+        assert_eq!(p.terms(), &[(2, GF4::One), (7, GF4::AlphaPlusOne)]);
+        assert_eq!(p.degree(), 7);
+        assert_eq!(p.weight(), 2);
+        assert_eq!(p.support(), vec![2, 7]);
+        assert_eq!(p.get_coefficient(7), GF4::AlphaPlusOne);
+        assert_eq!(p.get_coefficient(4), GF4::Zero);
+        assert!(SparsePolynomial::<GF4>::new().is_zero());
+        assert_eq!(SparsePolynomial::<GF4>::new().degree(), 0);
+    }
+
+    #[test]
+    fn test_sparse_polynomial_conversion() {
+        let dense: Polynomial<GF4> = "a*x^9 + (a+1)*x^3 + 1".parse().unwrap();
+        let sparse = SparsePolynomial::from_polynomial(&dense);
+        assert_eq!(sparse.terms(), &[(0, GF4::One), (3, GF4::AlphaPlusOne), (9, GF4::Alpha)]);
+        assert_eq!(sparse.to_polynomial(), dense);
+        assert!(SparsePolynomial::<GF4>::new().to_polynomial().is_zero());
+        assert!(SparsePolynomial::from_polynomial(&Polynomial::<GF4>::new()).is_zero());
+    }
+
+    #[test]
+    fn test_sparse_polynomial_mul_cyclic() {
+        let mut ctx = Context::from_seed([0x46; 32]);
+        let r = 13;
+        for _ in 0..20 {
+            let sparse: SparsePolynomial<GF4> = ctx.random_sparse_polynomial(r, 4).unwrap();
+            let dense: Polynomial<GF4> = ctx.random_polynomial(r - 1);
+            let expected = sparse.to_polynomial().mul(&dense).reduce_cyclic(r);
+            assert_eq!(sparse.mul_cyclic(&dense, r), expected);
+        }
+    }
+}
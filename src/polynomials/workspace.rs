@@ -0,0 +1,126 @@
+use std::mem::swap;
+use crate::galois_fields::GaloisField;
+use crate::polynomials::polynomial::Polynomial;
+
+// Reusable buffers for modular arithmetic in hot loops. After the first call
+// with operands of a given size, mul_mod and invert run without touching the
+// heap.
+pub struct PolynomialWorkspace<T: GaloisField> {
+    scratch: Vec<T>,
+    product: Polynomial<T>,
+    quotient: Polynomial<T>,
+    remainder: Polynomial<T>,
+    r_last: Polynomial<T>,
+    r_current: Polynomial<T>,
+    t_last: Polynomial<T>,
+    t_current: Polynomial<T>,
+}
+
+impl<T> PolynomialWorkspace<T>
+where T: GaloisField {
+
+    pub fn new() -> PolynomialWorkspace<T> {
+        PolynomialWorkspace::with_capacity(0)
+    }
+
+    // Preallocates buffers for operands of degree < capacity.
+    pub fn with_capacity(capacity: usize) -> PolynomialWorkspace<T> {
+        PolynomialWorkspace {
+            scratch: Vec::with_capacity(2 * capacity),
+            product: Polynomial::with_capacity(2 * capacity),
+            quotient: Polynomial::with_capacity(capacity),
+            remainder: Polynomial::with_capacity(capacity),
+            r_last: Polynomial::with_capacity(capacity),
+            r_current: Polynomial::with_capacity(capacity),
+            t_last: Polynomial::with_capacity(2 * capacity),
+            t_current: Polynomial::with_capacity(2 * capacity),
+        }
+    }
+
+    // out = a * b mod modulus; false if the modulus is zero.
+    pub fn mul_mod(&mut self, a: &Polynomial<T>, b: &Polynomial<T>, modulus: &Polynomial<T>, out: &mut Polynomial<T>) -> bool {
+        Polynomial::mul_into(a, b, &mut self.product, &mut self.scratch);
+        self.product.div_mod_into(modulus, &mut self.quotient, out)
+    }
+
+    // out = poly^-1 mod modulus, computed with the same extended Euclidean
+    // algorithm as Polynomial::invert; false if no inverse exists.
+    pub fn invert(&mut self, poly: &Polynomial<T>, modulus: &Polynomial<T>, out: &mut Polynomial<T>) -> bool {
+        if !poly.div_mod_into(modulus, &mut self.quotient, &mut self.r_current) {
+            return false;
+        }
+        self.r_last.assign(modulus);
+        self.t_last.set_zero();
+        self.t_current.set_one();
+
+        while !self.r_current.is_zero() {
+            self.r_last.div_mod_into(&self.r_current, &mut self.quotient, &mut self.remainder);
+            Polynomial::mul_into(&self.quotient, &self.t_current, &mut self.product, &mut self.scratch);
+            self.t_last.sub_assign(&self.product);
+            swap(&mut self.r_last, &mut self.r_current);
+            swap(&mut self.r_current, &mut self.remainder);
+            swap(&mut self.t_last, &mut self.t_current);
+        }
+
+        // r_last is now the gcd and t_last its Bezout coefficient for poly
+        if self.r_last.degree() != 0 {
+            return false;
+        }
+        let scale = T::generate_one().div(&self.r_last.leading_coefficient()).unwrap();
+        self.t_last.scale_assign(&scale);
+        self.t_last.div_mod_into(modulus, &mut self.quotient, out)
+    }
+}
+
+impl<T> Default for PolynomialWorkspace<T>
+where T: GaloisField {
+    fn default() -> Self {
+        PolynomialWorkspace::new()
+    }
+}
+
+#[cfg(test)]
+mod workspace_tests {
+    use crate::galois_fields::gf4_number::GF4;
+    use super::*;
+
+    fn modulus() -> Polynomial<GF4> {
+        // x^11 - 1
+        "x^11 + 1".parse().unwrap()
+    }
+
+    #[test]
+    fn test_workspace_mul_mod() {
+        let m = modulus();
+        let a: Polynomial<GF4> = "a*x^10 + x^7 + (a+1)*x^2 + 1".parse().unwrap();
+        let b: Polynomial<GF4> = "x^9 + a*x^3 + x".parse().unwrap();
+        let mut ws = PolynomialWorkspace::new();
+        let mut out = Polynomial::new();
+        assert!(ws.mul_mod(&a, &b, &m, &mut out));
+        assert_eq!(out, a.mul(&b).div_mod(&m).unwrap().1);
+        assert!(!ws.mul_mod(&a, &b, &Polynomial::new(), &mut out));
+    }
+
+    #[test]
+    fn test_workspace_invert() {
+        let m = modulus();
+        let mut ws = PolynomialWorkspace::with_capacity(12);
+        let mut out = Polynomial::new();
+        let polys: Vec<Polynomial<GF4>> = vec![
+            "a*x^10 + x^7 + (a+1)*x^2 + 1".parse().unwrap(),
+            "x^3 + x + a".parse().unwrap(),
+            "a+1".parse().unwrap(),
+            "x + 1".parse().unwrap(),
+            "x^12 + a*x".parse().unwrap(),
+            Polynomial::new(),
+        ];
+        for p in polys.iter() {
+            let expected = p.invert(&m);
+            assert_eq!(ws.invert(p, &m, &mut out), expected.is_some(), "{}", p);
+            if let Some(expected) = expected {
+                assert_eq!(out, expected);
+            }
+        }
+        assert!(!ws.invert(&polys[0], &Polynomial::new(), &mut out));
+    }
+}
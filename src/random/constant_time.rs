@@ -0,0 +1,136 @@
+use std::hint::black_box;
+use rand::RngCore;
+
+// Branch-free helpers for handling secret indices. Masks are either all ones
+// or all zeros. Masks pass through black_box so that the optimiser cannot see
+// they are boolean and turn the selects back into branches; black_box is only
+// a hint, so this is best-effort and not a guarantee of constant-time code.
+
+// All ones if a == b.
+pub fn ct_eq_mask(a: usize, b: usize) -> usize {
+    let x = a ^ b;
+    // the top bit of x | -x is set iff x != 0
+    let nonzero = (x | x.wrapping_neg()) >> (usize::BITS - 1);
+    black_box((nonzero ^ 1).wrapping_neg())
+}
+
+// a where the mask is set, b elsewhere.
+pub fn ct_select(mask: usize, a: usize, b: usize) -> usize {
+    let mask = black_box(mask);
+    (a & mask) | (b & !mask)
+}
+
+// table[index], reading every entry so that the memory access pattern on
+// table does not depend on index. Each step keeps either the running choice
+// or the current entry by indexing a two-entry local array with the low bit
+// of a mask, rather than branching. index must be in range.
+pub fn ct_lookup<T: Clone>(table: &[T], index: usize) -> T {
+    let mut chosen = table[0].clone();
+    for (k, entry) in table.iter().enumerate() {
+        let pair = [chosen, entry.clone()];
+        chosen = pair[ct_eq_mask(k, index) & 1].clone();
+    }
+    chosen
+}
+
+// Uniform in [0, bound) by Lemire's multiply-and-reject; bound must be
+// non-zero. The only data dependent branch is the rejection of a draw, which
+// is discarded and says nothing about the returned value.
+pub fn uniform_below<R: RngCore + ?Sized>(rng: &mut R, bound: u32) -> u32 {
+    assert!(bound > 0);
+    let threshold = bound.wrapping_neg() % bound;
+    loop {
+        let product = rng.next_u32() as u64 * bound as u64;
+        if (product as u32) >= threshold {
+            return (product >> 32) as u32;
+        }
+    }
+}
+
+// Uniformly random `weight`-subset of 0..length, in no particular order.
+// Sendrier's constant-time variant of Fisher-Yates, as used by BIKE: the
+// i-th position is drawn from i..length and replaced by i whenever a later
+// position already took the same value. Requires weight <= length <= u32::MAX.
+pub fn sample_support<R: RngCore + ?Sized>(rng: &mut R, length: usize, weight: usize) -> Vec<usize> {
+    assert!(weight <= length && length <= u32::MAX as usize);
+    let mut positions = vec![0usize; weight];
+    for i in (0..weight).rev() {
+        let mut position = i + uniform_below(rng, (length - i) as u32) as usize;
+        for later in &positions[i + 1..] {
+            position = ct_select(ct_eq_mask(position, *later), i, position);
+        }
+        positions[i] = position;
+    }
+    positions
+}
+
+// Dense vector of length `length` holding values[i] at positions[i] and zero
+// elsewhere. Every entry is compared against every position, so the memory
+// access pattern does not depend on the positions.
+pub fn expand_support(length: usize, positions: &[usize], values: &[usize]) -> Vec<usize> {
+    let mut dense = vec![0usize; length];
+    for (k, entry) in dense.iter_mut().enumerate() {
+        let mut value = 0;
+        for (position, v) in positions.iter().zip(values.iter()) {
+            value |= ct_eq_mask(k, *position) & *v;
+        }
+        *entry = value;
+    }
+    dense
+}
+
+#[cfg(test)]
+mod constant_time_tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+    use super::*;
+
+    #[test]
+    fn test_masks() {
+        assert_eq!(ct_eq_mask(5, 5), usize::MAX);
+        assert_eq!(ct_eq_mask(0, 0), usize::MAX);
+        assert_eq!(ct_eq_mask(5, 6), 0);
+        assert_eq!(ct_eq_mask(0, usize::MAX), 0);
+        assert_eq!(ct_eq_mask(1 << (usize::BITS - 1), 0), 0);
+        assert_eq!(ct_select(usize::MAX, 3, 4), 3);
+        assert_eq!(ct_select(0, 3, 4), 4);
+    }
+
+    #[test]
+    fn test_ct_lookup() {
+        let table = ["zero", "one", "two", "three"];
+        for (i, entry) in table.iter().enumerate() {
+            assert_eq!(ct_lookup(&table, i), *entry);
+        }
+    }
+
+    #[test]
+    fn test_uniform_below() {
+        let mut rng = ChaCha20Rng::from_seed([1; 32]);
+        for bound in [1, 2, 3, 7, 1000, u32::MAX] {
+            for _ in 0..100 {
+                assert!(uniform_below(&mut rng, bound) < bound);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sample_support() {
+        let mut rng = ChaCha20Rng::from_seed([2; 32]);
+        for length in 0..20 {
+            for weight in 0..=length {
+                let mut positions = sample_support(&mut rng, length, weight);
+                positions.sort();
+                positions.dedup();
+                assert_eq!(positions.len(), weight);
+                assert!(positions.iter().all(|p| *p < length));
+            }
+        }
+    }
+
+    #[test]
+    fn test_expand_support() {
+        assert_eq!(expand_support(6, &[4, 1], &[2, 3]), vec![0, 3, 0, 0, 2, 0]);
+        assert_eq!(expand_support(3, &[], &[]), vec![0, 0, 0]);
+    }
+}
@@ -1,38 +1,282 @@
-use rand::{CryptoRng, Rng, thread_rng};
-use rand::distributions::{Distribution, Uniform, uniform::SampleUniform};
+use rand::{thread_rng, CryptoRng, RngCore, SeedableRng};
 use rand::rngs::ThreadRng;
-use rand::seq::SliceRandom;
-use crate::{galois_fields, GaloisField};
-use crate::galois_fields::gf4_number::GF4;
+use std::fmt;
+use rand::seq::index;
+use rand_chacha::ChaCha20Rng;
+use crate::GaloisField;
+use crate::random::ctr_drbg::CtrDrbg;
+use crate::random::constant_time::{ct_lookup, expand_support, sample_support, uniform_below};
+use crate::random::keccak::{Shake, ShakeRng};
+use crate::polynomials::polynomial::Polynomial;
+use crate::polynomials::sparse_polynomial::SparsePolynomial;
 
-pub struct Context {
-    rng: ThreadRng,
+// Position of a seeded Context: the ChaCha20 seed and the number of 32-bit
+// words consumed so far. Serialises to 48 bytes (seed, then word position as
+// little-endian u128) so it can be written to simulation logs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContextState {
+    pub seed: [u8; 32],
+    pub word_pos: u128,
 }
 
-impl Context {
+impl ContextState {
+    pub fn to_bytes(&self) -> [u8; 48] {
+        let mut bytes = [0u8; 48];
+        bytes[..32].copy_from_slice(&self.seed);
+        bytes[32..].copy_from_slice(&self.word_pos.to_le_bytes());
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8; 48]) -> ContextState {
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&bytes[..32]);
+        let mut word_pos = [0u8; 16];
+        word_pos.copy_from_slice(&bytes[32..]);
+        ContextState {
+            seed,
+            word_pos: u128::from_le_bytes(word_pos),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SamplingError {
+    WeightExceedsLength { weight: usize, length: usize },
+}
+
+impl fmt::Display for SamplingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SamplingError::WeightExceedsLength { weight, length } =>
+                write!(f, "cannot place {} non-zero symbols in a vector of length {}", weight, length),
+        }
+    }
+}
+
+impl std::error::Error for SamplingError {}
+
+const STREAM_DOMAIN: &[u8] = b"mdpc-gf-4 context stream v1";
+
+// Samplers over any cryptographically secure generator; Context::new() keeps
+// using thread_rng().
+pub struct Context<R: RngCore + CryptoRng = ThreadRng> {
+    rng: R,
+}
+
+impl Context<ThreadRng> {
     pub fn new() -> Context {
         Context{
             rng: thread_rng(),
         }
     }
+}
+
+impl Context<ChaCha20Rng> {
+    // Deterministic Context: every sampler output is a function of the seed
+    // and the sequence of calls made so far.
+    pub fn from_seed(seed: [u8; 32]) -> Context<ChaCha20Rng> {
+        Context{
+            rng: ChaCha20Rng::from_seed(seed),
+        }
+    }
+
+    // Resumes a seeded Context exactly where `state()` was taken.
+    pub fn from_state(state: &ContextState) -> Context<ChaCha20Rng> {
+        let mut rng = ChaCha20Rng::from_seed(state.seed);
+        rng.set_word_pos(state.word_pos);
+        Context{
+            rng,
+        }
+    }
+
+    pub fn state(&self) -> ContextState {
+        ContextState {
+            seed: self.rng.get_seed(),
+            word_pos: self.rng.get_word_pos(),
+        }
+    }
+
+    pub fn reseed(&mut self, seed: [u8; 32]) {
+        self.rng = ChaCha20Rng::from_seed(seed);
+    }
+
+    // Seed of stream `stream` under `master_seed`: SHAKE256 of a domain
+    // separator, the master seed and the little-endian stream index.
+    pub fn stream_seed(master_seed: &[u8; 32], stream: u64) -> [u8; 32] {
+        let mut shake = Shake::shake256();
+        shake.update(STREAM_DOMAIN);
+        shake.update(master_seed);
+        shake.update(&stream.to_le_bytes());
+        let mut seed = [0u8; 32];
+        shake.finalize().squeeze(&mut seed);
+        seed
+    }
+
+    // Independent Context for trial or thread `stream`. It depends only on the
+    // master seed and the index, so a simulation gives the same trial k the
+    // same randomness however the trials are spread over threads.
+    pub fn for_stream(master_seed: &[u8; 32], stream: u64) -> Context<ChaCha20Rng> {
+        Context::from_seed(Context::stream_seed(master_seed, stream))
+    }
+
+    // Child stream of this Context's seed; how much of the parent has been
+    // consumed does not matter. Children can be split further.
+    pub fn child(&self, stream: u64) -> Context<ChaCha20Rng> {
+        Context::for_stream(&self.rng.get_seed(), stream)
+    }
+}
+
+impl Context<ShakeRng> {
+    // Expands a seed with SHAKE256, as the BIKE reference implementation does.
+    pub fn from_shake256_seed(seed: &[u8]) -> Context<ShakeRng> {
+        let mut shake = Shake::shake256();
+        shake.update(seed);
+        Context::from_rng(shake.finalize())
+    }
+}
+
+impl Context<CtrDrbg> {
+    // NIST PQC harness: randombytes_init(seed, NULL, 256), with the 48-byte
+    // seed of a KAT .req entry.
+    pub fn from_kat_seed(seed: &[u8; 48]) -> Context<CtrDrbg> {
+        Context::from_rng(CtrDrbg::new(seed, None))
+    }
+}
+
+impl<R> Context<R>
+where R: RngCore + CryptoRng {
+
+    pub fn from_rng(rng: R) -> Context<R> {
+        Context{
+            rng,
+        }
+    }
+
+    pub fn rng_mut(&mut self) -> &mut R {
+        &mut self.rng
+    }
+
+    pub fn into_rng(self) -> R {
+        self.rng
+    }
 
     pub fn random_vector<T: GaloisField>(&mut self, length: usize) -> Vec<T> {
         let mut v = Vec::new();
-        for i in 0..length {
+        for _ in 0..length {
             v.push(T::generate_random(&mut self.rng));
         }
         v
     }
 
-    pub fn random_error_vector<T: GaloisField>(&mut self, length: usize, weight: usize) -> Vec<T> {
+    // Exactly `weight` non-zero symbols: the support is a uniformly random
+    // `weight`-subset of the positions and each value is uniform over T \ {0}.
+    // Runs in variable time; secret vectors should use constant_time_error_vector.
+    pub fn random_error_vector<T: GaloisField>(&mut self, length: usize, weight: usize) -> Result<Vec<T>, SamplingError> {
+        if weight > length {
+            return Err(SamplingError::WeightExceedsLength { weight, length });
+        }
         let mut v: Vec<T> = vec![T::generate_zero(); length];
-        for i in 0..weight {
-            let val: T = T::generate_random(&mut self.rng);
+        for position in index::sample(&mut self.rng, length, weight) {
+            v[position] = self.random_nonzero();
+        }
+        Ok(v)
+    }
+
+    // Constant-time counterpart of `random_error_vector`, for secret error
+    // vectors and key supports. Positions and values are handled as indices
+    // with branch-free code, and each symbol is picked from T::elements() by
+    // scanning all of them. Best-effort: see random::constant_time.
+    pub fn constant_time_error_vector<T: GaloisField>(&mut self, length: usize, weight: usize) -> Result<Vec<T>, SamplingError> {
+        let (positions, values) = self.constant_time_sparse_error::<T>(length, weight)?;
+        let elements = T::elements();
+        Ok(expand_support(length, &positions, &values).into_iter().map(|i| ct_lookup(&elements, i)).collect())
+    }
 
-            v[i] = T::generate_random(&mut self.rng);
+    // Unordered positions and indices into T::elements() (never 0, i.e. never
+    // the zero element) of a uniformly random weight-`weight` error vector.
+    pub fn constant_time_sparse_error<T: GaloisField>(&mut self, length: usize, weight: usize) -> Result<(Vec<usize>, Vec<usize>), SamplingError> {
+        if weight > length {
+            return Err(SamplingError::WeightExceedsLength { weight, length });
+        }
+        let positions = sample_support(&mut self.rng, length, weight);
+        let nonzero = (T::order() - 1) as u32;
+        let values = (0..weight).map(|_| 1 + uniform_below(&mut self.rng, nonzero) as usize).collect();
+        Ok((positions, values))
+    }
+
+    fn random_nonzero<T: GaloisField>(&mut self) -> T {
+        loop {
+            let val = T::generate_random(&mut self.rng);
+            if !val.is_zero() {
+                return val;
+            }
+        }
+    }
+
+    // Uniformly random among polynomials of exactly the given degree.
+    pub fn random_polynomial<T: GaloisField>(&mut self, degree: usize) -> Polynomial<T> {
+        let mut coefficients: Vec<T> = self.random_vector(degree);
+        coefficients.push(self.random_nonzero());
+        Polynomial::new_from_coefficients(coefficients)
+    }
+
+    pub fn random_monic_polynomial<T: GaloisField>(&mut self, degree: usize) -> Polynomial<T> {
+        let mut coefficients: Vec<T> = self.random_vector(degree);
+        coefficients.push(T::generate_one());
+        Polynomial::new_from_coefficients(coefficients)
+    }
+
+    // Exactly `weight` non-zero coefficients at uniformly chosen positions
+    // below `length`.
+    pub fn random_fixed_weight_polynomial<T: GaloisField>(&mut self, length: usize, weight: usize) -> Result<Polynomial<T>, SamplingError> {
+        Ok(Polynomial::new_from_coefficients(self.random_error_vector(length, weight)?))
+    }
+
+    // Uniformly random `weight`-subset of 0..length in increasing order.
+    // rand::seq::index::sample picks the algorithm: Floyd's, O(weight^2), for
+    // small weights; a partial shuffle, O(length), when weight is a large
+    // fraction of length; rejection with a hash set, expected O(weight),
+    // otherwise. Sorting adds O(weight log weight). For MDPC parameters
+    // (weight around 100, length around 10^4) that is Floyd's, independent of
+    // length. Variable time.
+    pub fn random_sparse_support(&mut self, length: usize, weight: usize) -> Result<Vec<usize>, SamplingError> {
+        if weight > length {
+            return Err(SamplingError::WeightExceedsLength { weight, length });
+        }
+        let mut support = index::sample(&mut self.rng, length, weight).into_vec();
+        support.sort_unstable();
+        Ok(support)
+    }
+
+    // Same distribution as random_fixed_weight_polynomial, without building
+    // the dense coefficient vector.
+    pub fn random_sparse_polynomial<T: GaloisField>(&mut self, length: usize, weight: usize) -> Result<SparsePolynomial<T>, SamplingError> {
+        let support = self.random_sparse_support(length, weight)?;
+        let terms = support.into_iter().map(|i| (i, self.random_nonzero())).collect();
+        Ok(SparsePolynomial::new_from_terms(terms))
+    }
+
+    // Uniformly random unit of F[x]/(x^r - 1), by rejection sampling; None if r == 0.
+    pub fn random_invertible_polynomial<T: GaloisField>(&mut self, r: usize) -> Option<Polynomial<T>> {
+        if r == 0 {
+            return None;
+        }
+        let mut modulus = vec![T::generate_zero(); r + 1];
+        modulus[0] = T::generate_zero().sub(&T::generate_one());
+        modulus[r] = T::generate_one();
+        let modulus = Polynomial::new_from_coefficients(modulus);
+        loop {
+            let candidate = Polynomial::new_from_coefficients(self.random_vector(r));
+            if candidate.invert(&modulus).is_some() {
+                return Some(candidate);
+            }
         }
-        v.shuffle(&mut self.rng);
-        v
+    }
+}
+
+impl Default for Context<ThreadRng> {
+    fn default() -> Self {
+        Context::new()
     }
 }
 
@@ -49,4 +293,291 @@ impl<T: SampleUniform + ?Sized> FiniteFieldGenerator<T> {
         self.distribution.sample(&mut self.rng)
     }
 }
-*/
\ No newline at end of file
+*/
+
+#[cfg(test)]
+mod context_tests {
+    use crate::galois_fields::gf4_number::GF4;
+    use crate::random::statistics::{chi_square_critical_value, chi_square_independence};
+    use super::*;
+
+    #[test]
+    fn test_random_polynomial() {
+        let mut ctx = Context::from_seed([0x39; 32]);
+        for degree in 0..10 {
+            let p: Polynomial<GF4> = ctx.random_polynomial(degree);
+            assert_eq!(p.degree(), degree);
+            assert!(!p.is_zero());
+        }
+    }
+
+    #[test]
+    fn test_random_monic_polynomial() {
+        let mut ctx = Context::from_seed([0x3a; 32]);
+        for degree in 0..10 {
+            let p: Polynomial<GF4> = ctx.random_monic_polynomial(degree);
+            assert_eq!(p.degree(), degree);
+            assert!(p.leading_coefficient().is_one());
+        }
+    }
+
+    #[test]
+    fn test_random_fixed_weight_polynomial() {
+        let mut ctx = Context::from_seed([0x3b; 32]);
+        for _ in 0..100 {
+            let p: Polynomial<GF4> = ctx.random_fixed_weight_polynomial(50, 7).unwrap();
+            assert_eq!(p.weight(), 7);
+            assert!(p.degree() < 50);
+        }
+        let full: Polynomial<GF4> = ctx.random_fixed_weight_polynomial(5, 5).unwrap();
+        assert_eq!(full.weight(), 5);
+        assert!(ctx.random_fixed_weight_polynomial::<GF4>(5, 0).unwrap().is_zero());
+        assert_eq!(ctx.random_fixed_weight_polynomial::<GF4>(5, 6), Err(SamplingError::WeightExceedsLength { weight: 6, length: 5 }));
+    }
+
+    #[test]
+    fn test_random_error_vector_weight() {
+        let mut ctx = Context::from_seed([0x42; 32]);
+        for length in 0..12 {
+            for weight in 0..=length {
+                let e: Vec<GF4> = ctx.random_error_vector(length, weight).unwrap();
+                assert_eq!(e.len(), length);
+                assert_eq!(e.iter().filter(|x| !x.is_zero()).count(), weight);
+            }
+        }
+        assert_eq!(ctx.random_error_vector::<GF4>(3, 4), Err(SamplingError::WeightExceedsLength { weight: 4, length: 3 }));
+    }
+
+    #[test]
+    fn test_constant_time_error_vector() {
+        let mut ctx = Context::from_seed([0x45; 32]);
+        for length in 0..12 {
+            for weight in 0..=length {
+                let e: Vec<GF4> = ctx.constant_time_error_vector(length, weight).unwrap();
+                assert_eq!(e.len(), length);
+                assert_eq!(e.iter().filter(|x| !x.is_zero()).count(), weight);
+            }
+        }
+        assert_eq!(ctx.constant_time_error_vector::<GF4>(3, 4), Err(SamplingError::WeightExceedsLength { weight: 4, length: 3 }));
+
+        let (positions, values) = ctx.constant_time_sparse_error::<GF4>(30, 6).unwrap();
+        assert_eq!(positions.len(), 6);
+        assert!(values.iter().all(|v| (1..4).contains(v)));
+    }
+
+    #[test]
+    fn test_random_sparse_support() {
+        let mut ctx = Context::from_seed([0x47; 32]);
+        for _ in 0..100 {
+            let support = ctx.random_sparse_support(10007, 71).unwrap();
+            assert_eq!(support.len(), 71);
+            assert!(support.windows(2).all(|w| w[0] < w[1]));
+            assert!(support[70] < 10007);
+        }
+        assert_eq!(ctx.random_sparse_support(4, 4).unwrap(), vec![0, 1, 2, 3]);
+        assert!(ctx.random_sparse_support(4, 5).is_err());
+
+        let p: SparsePolynomial<GF4> = ctx.random_sparse_polynomial(10007, 71).unwrap();
+        assert_eq!(p.weight(), 71);
+        assert!(p.degree() < 10007);
+        assert_eq!(p.to_polynomial().weight(), 71);
+        assert!(ctx.random_sparse_polynomial::<GF4>(4, 5).is_err());
+    }
+
+    #[test]
+    fn test_random_invertible_polynomial() {
+        let mut ctx = Context::from_seed([0x3c; 32]);
+        let r = 11;
+        let modulus: Polynomial<GF4> = "x^11 + 1".parse().unwrap();
+        for _ in 0..20 {
+            let p: Polynomial<GF4> = ctx.random_invertible_polynomial(r).unwrap();
+            assert!(p.degree() < r);
+            let inv = p.invert(&modulus).unwrap();
+            assert!(p.mul(&inv).reduce_cyclic(r).is_one());
+        }
+        assert!(ctx.random_invertible_polynomial::<GF4>(0).is_none());
+    }
+
+    fn sample_everything<R: RngCore + CryptoRng>(ctx: &mut Context<R>) -> (Vec<GF4>, Vec<GF4>, Vec<Polynomial<GF4>>) {
+        (
+            ctx.random_vector(20),
+            ctx.random_error_vector(20, 4).unwrap(),
+            vec![
+                ctx.random_polynomial(8),
+                ctx.random_fixed_weight_polynomial(30, 5).unwrap(),
+                ctx.random_invertible_polynomial(11).unwrap(),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_from_seed_is_deterministic() {
+        let mut a = Context::from_seed([7; 32]);
+        let mut b = Context::from_seed([7; 32]);
+        let mut c = Context::from_seed([8; 32]);
+        for _ in 0..5 {
+            let sa = sample_everything(&mut a);
+            assert_eq!(sa, sample_everything(&mut b));
+            assert_ne!(sa, sample_everything(&mut c));
+        }
+    }
+
+    #[test]
+    fn test_from_shake256_seed() {
+        let mut a = Context::from_shake256_seed(b"seed");
+        let mut b = Context::from_shake256_seed(b"seed");
+        let mut c = Context::from_shake256_seed(b"seee");
+        let sa = sample_everything(&mut a);
+        assert_eq!(sa, sample_everything(&mut b));
+        assert_ne!(sa, sample_everything(&mut c));
+
+        // The Context consumes the raw SHAKE256 stream.
+        let mut ctx = Context::from_shake256_seed(b"");
+        let mut raw = Shake::shake256().finalize();
+        let expected: Vec<GF4> = (0..10).map(|_| GF4::generate_random(&mut raw)).collect();
+        assert_eq!(ctx.random_vector::<GF4>(10), expected);
+    }
+
+    #[test]
+    fn test_from_kat_seed() {
+        let seed: [u8; 48] = core::array::from_fn(|i| i as u8);
+        let mut a = Context::from_kat_seed(&seed);
+        let mut b = Context::from_kat_seed(&seed);
+        assert_eq!(sample_everything(&mut a), sample_everything(&mut b));
+
+        // The Context consumes randombytes calls of the underlying DRBG.
+        let mut ctx = Context::from_kat_seed(&seed);
+        let mut raw = CtrDrbg::new(&seed, None);
+        let expected: Vec<GF4> = (0..10).map(|_| GF4::generate_random(&mut raw)).collect();
+        assert_eq!(ctx.random_vector::<GF4>(10), expected);
+    }
+
+    #[test]
+    fn test_state_roundtrip() {
+        let mut ctx = Context::from_seed([42; 32]);
+        assert_eq!(ctx.state(), ContextState { seed: [42; 32], word_pos: 0 });
+        sample_everything(&mut ctx);
+        let state = ctx.state();
+        assert!(state.word_pos > 0);
+        assert_eq!(ContextState::from_bytes(&state.to_bytes()), state);
+
+        let mut resumed = Context::from_state(&ContextState::from_bytes(&state.to_bytes()));
+        assert_eq!(sample_everything(&mut ctx), sample_everything(&mut resumed));
+        assert_eq!(ctx.state(), resumed.state());
+    }
+
+    #[test]
+    fn test_streams_do_not_depend_on_thread_count() {
+        let master = [0x49; 32];
+        let trials = 24u64;
+        let run = |threads: u64| -> Vec<Vec<GF4>> {
+            let mut results = vec![Vec::new(); trials as usize];
+            let handles: Vec<_> = (0..threads)
+                .map(|t| std::thread::spawn(move || {
+                    (t..trials)
+                        .step_by(threads as usize)
+                        .map(|k| (k, Context::for_stream(&master, k).random_error_vector::<GF4>(40, 6).unwrap()))
+                        .collect::<Vec<_>>()
+                }))
+                .collect();
+            for handle in handles {
+                for (k, e) in handle.join().unwrap() {
+                    results[k as usize] = e;
+                }
+            }
+            results
+        };
+        let sequential = run(1);
+        assert_eq!(run(3), sequential);
+        assert_eq!(run(8), sequential);
+        for (k, e) in sequential.iter().enumerate().skip(1) {
+            assert_ne!(e, &sequential[k - 1]);
+        }
+    }
+
+    #[test]
+    fn test_stream_seeds() {
+        let master = [1; 32];
+        assert_eq!(Context::stream_seed(&master, 0), Context::stream_seed(&master, 0));
+        assert_ne!(Context::stream_seed(&master, 0), Context::stream_seed(&master, 1));
+        assert_ne!(Context::stream_seed(&master, 0), Context::stream_seed(&[2; 32], 0));
+        assert_ne!(Context::stream_seed(&master, 0), master);
+
+        // a child ignores how far its parent has advanced
+        let mut parent = Context::from_seed(master);
+        let before = parent.child(5).random_vector::<GF4>(16);
+        sample_everything(&mut parent);
+        assert_eq!(parent.child(5).random_vector::<GF4>(16), before);
+        assert_eq!(Context::for_stream(&master, 5).random_vector::<GF4>(16), before);
+        assert_ne!(parent.child(5).child(0).random_vector::<GF4>(16), before);
+    }
+
+    #[test]
+    fn test_streams_are_independent() {
+        // Symbols at the same offset of neighbouring streams, at significance
+        // level SIGNIFICANCE_LEVEL with a fixed master seed.
+        let master = [0x50; 32];
+        let mut table = vec![vec![0; 4]; 4];
+        for k in 0..100 {
+            let a: Vec<GF4> = Context::for_stream(&master, k).random_vector(100);
+            let b: Vec<GF4> = Context::for_stream(&master, k + 1).random_vector(100);
+            for (x, y) in a.iter().zip(b.iter()) {
+                table[x.to_number() as usize][y.to_number() as usize] += 1;
+            }
+        }
+        let (statistic, degrees_of_freedom) = chi_square_independence(&table);
+        assert!(statistic < chi_square_critical_value(degrees_of_freedom), "{:?}", table);
+    }
+
+    #[test]
+    fn test_reseed() {
+        let mut fresh = Context::from_seed([3; 32]);
+        let mut seeded = Context::from_seed([9; 32]);
+        sample_everything(&mut seeded);
+        seeded.reseed([3; 32]);
+        assert_eq!(sample_everything(&mut fresh), sample_everything(&mut seeded));
+    }
+
+    // Counts the words drawn, to check that the Context only uses the caller's generator.
+    struct CountingRng {
+        inner: ChaCha20Rng,
+        words: usize,
+    }
+
+    impl RngCore for CountingRng {
+        fn next_u32(&mut self) -> u32 {
+            self.words += 1;
+            self.inner.next_u32()
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.words += 2;
+            self.inner.next_u64()
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            self.words += dest.len().div_ceil(4);
+            self.inner.fill_bytes(dest)
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for CountingRng {}
+
+    #[test]
+    fn test_caller_supplied_rng() {
+        let mut ctx = Context::from_rng(CountingRng { inner: ChaCha20Rng::from_seed([5; 32]), words: 0 });
+        let with_counter = sample_everything(&mut ctx);
+        assert!(ctx.rng_mut().words > 0);
+        let counter = ctx.into_rng();
+        assert_eq!(counter.inner.get_word_pos() as usize, counter.words);
+        assert_eq!(with_counter, sample_everything(&mut Context::from_seed([5; 32])));
+
+        let mut std_ctx = Context::from_rng(rand::rngs::StdRng::seed_from_u64(1));
+        assert_eq!(std_ctx.random_vector::<GF4>(8).len(), 8);
+    }
+}
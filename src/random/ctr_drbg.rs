@@ -0,0 +1,166 @@
+use rand::{CryptoRng, RngCore};
+use crate::random::aes::Aes256;
+
+// AES-256 CTR_DRBG without derivation function, exactly as in rng.c of the
+// NIST PQC reference harness, so that KAT .req/.rsp files can be reproduced.
+// Every call to `randombytes` encrypts whole counter blocks, discards the
+// unused tail of the last one and then updates key and counter; the output
+// therefore depends on how requests are split, just as in rng.c.
+#[derive(Clone)]
+pub struct CtrDrbg {
+    key: [u8; 32],
+    v: [u8; 16],
+    reseed_counter: u64,
+}
+
+fn increment(v: &mut [u8; 16]) {
+    for b in v.iter_mut().rev() {
+        let (sum, overflow) = b.overflowing_add(1);
+        *b = sum;
+        if !overflow {
+            break;
+        }
+    }
+}
+
+impl CtrDrbg {
+    // randombytes_init(entropy_input, personalization_string, 256)
+    pub fn new(entropy_input: &[u8; 48], personalization_string: Option<&[u8; 48]>) -> CtrDrbg {
+        let mut seed_material = *entropy_input;
+        if let Some(personalization) = personalization_string {
+            for (s, p) in seed_material.iter_mut().zip(personalization.iter()) {
+                *s ^= p;
+            }
+        }
+        let mut drbg = CtrDrbg {
+            key: [0; 32],
+            v: [0; 16],
+            reseed_counter: 0,
+        };
+        drbg.update(Some(&seed_material));
+        drbg.reseed_counter = 1;
+        drbg
+    }
+
+    // AES256_CTR_DRBG_Update
+    fn update(&mut self, provided_data: Option<&[u8; 48]>) {
+        let aes = Aes256::new(&self.key);
+        let mut temp = [0u8; 48];
+        for block in temp.chunks_mut(16) {
+            increment(&mut self.v);
+            block.copy_from_slice(&aes.encrypt_block(&self.v));
+        }
+        if let Some(data) = provided_data {
+            for (t, d) in temp.iter_mut().zip(data.iter()) {
+                *t ^= d;
+            }
+        }
+        self.key.copy_from_slice(&temp[..32]);
+        self.v.copy_from_slice(&temp[32..]);
+    }
+
+    pub fn randombytes(&mut self, out: &mut [u8]) {
+        let aes = Aes256::new(&self.key);
+        for chunk in out.chunks_mut(16) {
+            increment(&mut self.v);
+            let block = aes.encrypt_block(&self.v);
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+        self.update(None);
+        self.reseed_counter += 1;
+    }
+
+    pub fn reseed_counter(&self) -> u64 {
+        self.reseed_counter
+    }
+}
+
+// next_u32 and next_u64 are single randombytes calls of 4 and 8 bytes,
+// read little-endian.
+impl RngCore for CtrDrbg {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.randombytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.randombytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.randombytes(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.randombytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for CtrDrbg {}
+
+#[cfg(test)]
+mod ctr_drbg_tests {
+    use crate::random::hex::to_hex;
+    use super::*;
+
+    fn kat_entropy() -> [u8; 48] {
+        core::array::from_fn(|i| i as u8)
+    }
+
+    #[test]
+    fn test_ctr_drbg_kat_seeds() {
+        // PQCgenKAT: entropy_input = 0, 1, ..., 47; the per-count seeds of
+        // every published PQC .req file, in the files' uppercase hex.
+        let mut drbg = CtrDrbg::new(&kat_entropy(), None);
+        let expected = [
+            "061550234D158C5EC95595FE04EF7A25767F2E24CC2BC479D09D86DC9ABCFDE7056A8C266F9EF97ED08541DBD2E1FFA1",
+            "D81C4D8D734FCBFBEADE3D3F8A039FAA2A2C9957E835AD55B22E75BF57BB556AC81ADDE6AEEB4A5A875C3BFCADFA958F",
+            "64335BF29E5DE62842C941766BA129B0643B5E7121CA26CFC190EC7DC3543830557FDD5C03CF123A456D48EFEA43C868",
+        ];
+        for seed in expected {
+            let mut out = [0u8; 48];
+            drbg.randombytes(&mut out);
+            assert_eq!(to_hex(&out).to_uppercase(), seed);
+        }
+        assert_eq!(drbg.reseed_counter(), 4);
+    }
+
+    #[test]
+    fn test_ctr_drbg_reseeded_with_kat_seed() {
+        // What a scheme's crypto_kem_keypair sees for count = 0.
+        let mut seed = [0u8; 48];
+        CtrDrbg::new(&kat_entropy(), None).randombytes(&mut seed);
+        let mut drbg = CtrDrbg::new(&seed, None);
+        let mut out = [0u8; 32];
+        drbg.randombytes(&mut out);
+        assert_eq!(to_hex(&out).to_uppercase(), "7C9935A0B07694AA0C6D10E4DB6B1ADD2FD81A25CCB148032DCD739936737F2D");
+        // a partial block discards its tail
+        let mut short = [0u8; 5];
+        drbg.randombytes(&mut short);
+        assert_eq!(to_hex(&short).to_uppercase(), "8626ED79D4");
+        assert_eq!(drbg.next_u32(), u32::from_le_bytes([0xEF, 0xB3, 0xB2, 0x4D]));
+    }
+
+    #[test]
+    fn test_ctr_drbg_personalization() {
+        let personalization: [u8; 48] = core::array::from_fn(|i| 100 + i as u8);
+        let mut drbg = CtrDrbg::new(&kat_entropy(), Some(&personalization));
+        let mut out = [0u8; 16];
+        drbg.randombytes(&mut out);
+        assert_eq!(to_hex(&out).to_uppercase(), "1D882D8A4BB8DD10E3C0271C8BF5C16F");
+    }
+
+    #[test]
+    fn test_increment_carries() {
+        let mut v = [0xFF; 16];
+        v[0] = 0x01;
+        increment(&mut v);
+        let mut expected = [0u8; 16];
+        expected[0] = 0x02;
+        assert_eq!(v, expected);
+    }
+}
@@ -0,0 +1,30 @@
+// Lowercase hex encoding, for the known-answer tests and the rng log format.
+
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+// None unless s is an even number of lowercase hex digits. from_str_radix on
+// its own would also take uppercase digits and a leading '+'.
+pub fn from_hex(s: &str) -> Option<Vec<u8>> {
+    let lower_hex = s.bytes().all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b));
+    if !s.len().is_multiple_of(2) || !lower_hex {
+        return None;
+    }
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok()).collect()
+}
+
+#[cfg(test)]
+mod hex_tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_roundtrip() {
+        assert_eq!(to_hex(&[0x00, 0xab, 0x10]), "00ab10");
+        assert_eq!(from_hex("00ab10"), Some(vec![0x00, 0xab, 0x10]));
+        assert_eq!(from_hex(""), Some(vec![]));
+        for bad in ["0", "AB", "+f", "0g", "é0"] {
+            assert_eq!(from_hex(bad), None, "{}", bad);
+        }
+    }
+}
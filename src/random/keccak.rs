@@ -0,0 +1,228 @@
+use rand::{CryptoRng, RngCore};
+
+// Keccak-f[1600] and the SHAKE extendable-output functions of FIPS 202.
+
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808A, 0x8000000080008000,
+    0x000000000000808B, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+    0x000000000000008A, 0x0000000000000088, 0x0000000080008009, 0x000000008000000A,
+    0x000000008000808B, 0x800000000000008B, 0x8000000000008089, 0x8000000000008003,
+    0x8000000000008002, 0x8000000000000080, 0x000000000000800A, 0x800000008000000A,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+];
+
+// Rotation offsets of rho, indexed by x + 5 * y.
+const ROTATIONS: [u32; 25] = [
+    0, 1, 62, 28, 27,
+    36, 44, 6, 55, 20,
+    3, 10, 43, 25, 39,
+    41, 45, 15, 21, 8,
+    18, 2, 61, 56, 14,
+];
+
+// The state is 25 lanes, lane (x, y) at index x + 5 * y.
+pub fn keccak_f1600(state: &mut [u64; 25]) {
+    for round_constant in ROUND_CONSTANTS {
+        // theta
+        let mut c = [0u64; 5];
+        for x in 0..5 {
+            c[x] = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[x + 5 * y] ^= d;
+            }
+        }
+
+        // rho and pi: B[y, 2x + 3y] = rot(A[x, y])
+        let mut b = [0u64; 25];
+        for x in 0..5 {
+            for y in 0..5 {
+                b[y + 5 * ((2 * x + 3 * y) % 5)] = state[x + 5 * y].rotate_left(ROTATIONS[x + 5 * y]);
+            }
+        }
+
+        // chi
+        for y in 0..5 {
+            for x in 0..5 {
+                state[x + 5 * y] = b[x + 5 * y] ^ (!b[(x + 1) % 5 + 5 * y] & b[(x + 2) % 5 + 5 * y]);
+            }
+        }
+
+        // iota
+        state[0] ^= round_constant;
+    }
+}
+
+fn xor_byte(state: &mut [u64; 25], position: usize, byte: u8) {
+    state[position / 8] ^= (byte as u64) << (8 * (position % 8));
+}
+
+fn state_byte(state: &[u64; 25], position: usize) -> u8 {
+    (state[position / 8] >> (8 * (position % 8))) as u8
+}
+
+// Absorbing phase of SHAKE128 / SHAKE256.
+#[derive(Clone)]
+pub struct Shake {
+    state: [u64; 25],
+    rate: usize,
+    position: usize,
+}
+
+impl Shake {
+    pub fn shake128() -> Shake {
+        Shake { state: [0; 25], rate: 168, position: 0 }
+    }
+
+    pub fn shake256() -> Shake {
+        Shake { state: [0; 25], rate: 136, position: 0 }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            xor_byte(&mut self.state, self.position, byte);
+            self.position += 1;
+            if self.position == self.rate {
+                keccak_f1600(&mut self.state);
+                self.position = 0;
+            }
+        }
+    }
+
+    // Pads with the SHAKE domain separator and switches to squeezing.
+    pub fn finalize(mut self) -> ShakeRng {
+        xor_byte(&mut self.state, self.position, 0x1F);
+        xor_byte(&mut self.state, self.rate - 1, 0x80);
+        keccak_f1600(&mut self.state);
+        ShakeRng { state: self.state, rate: self.rate, position: 0 }
+    }
+}
+
+// Squeezing phase: an endless output stream, usable as a random source.
+#[derive(Clone)]
+pub struct ShakeRng {
+    state: [u64; 25],
+    rate: usize,
+    position: usize,
+}
+
+impl ShakeRng {
+    pub fn squeeze(&mut self, out: &mut [u8]) {
+        for byte in out.iter_mut() {
+            if self.position == self.rate {
+                keccak_f1600(&mut self.state);
+                self.position = 0;
+            }
+            *byte = state_byte(&self.state, self.position);
+            self.position += 1;
+        }
+    }
+}
+
+impl RngCore for ShakeRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.squeeze(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.squeeze(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.squeeze(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.squeeze(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for ShakeRng {}
+
+pub fn shake128(data: &[u8], output_length: usize) -> Vec<u8> {
+    let mut shake = Shake::shake128();
+    shake.update(data);
+    let mut out = vec![0u8; output_length];
+    shake.finalize().squeeze(&mut out);
+    out
+}
+
+pub fn shake256(data: &[u8], output_length: usize) -> Vec<u8> {
+    let mut shake = Shake::shake256();
+    shake.update(data);
+    let mut out = vec![0u8; output_length];
+    shake.finalize().squeeze(&mut out);
+    out
+}
+
+#[cfg(test)]
+mod keccak_tests {
+    use crate::random::hex::to_hex;
+    use super::*;
+
+    fn message_200() -> Vec<u8> {
+        (0..200).map(|i| (i % 251) as u8).collect()
+    }
+
+    #[test]
+    fn test_keccak_f1600_zero_state() {
+        // First lanes of Keccak-f[1600] applied to the all-zero state.
+        let mut state = [0u64; 25];
+        keccak_f1600(&mut state);
+        assert_eq!(state[0], 0xF1258F7940E1DDE7);
+        assert_eq!(state[1], 0x84D5CCF933C0478A);
+        assert_eq!(state[24], 0xEAF1FF7B5CECA249);
+    }
+
+    #[test]
+    fn test_shake128_vectors() {
+        assert_eq!(to_hex(&shake128(b"", 32)), "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26");
+        assert_eq!(to_hex(&shake128(b"abc", 32)), "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8");
+        let out = shake128(&message_200(), 400);
+        assert_eq!(to_hex(&out[..32]), "0c4234ca1e31801ae606f8b8d8e0665c66f42a21d601c2681858a92c79ad5d69");
+        assert_eq!(to_hex(&out[368..]), "fee6ee2f71a7379d317fde494491ec873fa6be71b2cf3888ff169e5a98c7fa85");
+    }
+
+    #[test]
+    fn test_shake256_vectors() {
+        assert_eq!(to_hex(&shake256(b"", 32)), "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f");
+        assert_eq!(to_hex(&shake256(b"abc", 32)), "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739");
+        let out = shake256(&message_200(), 400);
+        assert_eq!(to_hex(&out[..32]), "4ee1ca03272b05d3bfb1e1c79a967f823b9fc5e4bb3987b1ba9e9cb5afb07a5e");
+        assert_eq!(to_hex(&out[368..]), "7354e3c91c456d086fe066896ad6c6ee2b7168357432a603b2142f74dadd2b5a");
+    }
+
+    #[test]
+    fn test_shake_incremental() {
+        let message = message_200();
+        let mut shake = Shake::shake256();
+        for chunk in message.chunks(7) {
+            shake.update(chunk);
+        }
+        let mut reader = shake.finalize();
+        let mut out = Vec::new();
+        for length in [1, 135, 2, 200, 62] {
+            let mut part = vec![0u8; length];
+            reader.squeeze(&mut part);
+            out.extend(part);
+        }
+        assert_eq!(out, shake256(&message, 400));
+    }
+
+    #[test]
+    fn test_shake_rng() {
+        let mut shake = Shake::shake128();
+        shake.update(b"abc");
+        let mut rng = shake.finalize();
+        let expected = shake128(b"abc", 12);
+        assert_eq!(rng.next_u32(), u32::from_le_bytes(expected[..4].try_into().unwrap()));
+        assert_eq!(rng.next_u64(), u64::from_le_bytes(expected[4..].try_into().unwrap()));
+    }
+}
@@ -1,3 +1,11 @@
-use crate::GaloisField;
-
 pub mod context;
+pub mod keccak;
+pub mod constant_time;
+pub mod aes;
+pub mod ctr_drbg;
+#[cfg(test)]
+pub(crate) mod statistics;
+#[cfg(any(test, feature = "rng-replay"))]
+pub mod recording;
+#[cfg(any(test, feature = "rng-replay"))]
+pub(crate) mod hex;
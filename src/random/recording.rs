@@ -0,0 +1,494 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use rand::{CryptoRng, RngCore};
+use crate::random::hex::{from_hex, to_hex};
+
+// Recording and replay of random draws, to rerun a failed trial bit-exactly:
+// run it with Context::from_rng(RecordingRng::create(rng, path)?), which
+// appends every draw to the file as it happens, and later run the same code
+// with Context::from_rng(ReplayRng::new(RngLog::load(path)?)). Replay
+// reproduces everything computed from the Context's randomness as long as the
+// code requests the same draws in the same order.
+//
+// Both generators implement CryptoRng so that they can drive a Context, but
+// the log is plaintext key material, so the module is only built for tests
+// and with the rng-replay feature.
+//
+// Log format, version 1. A UTF-8 text file of lines separated by '\n':
+//
+//     mdpc-gf-4 rng log v1
+//     u32 0a1b2c3d
+//     u64 00000000deadbeef
+//     bytes 00ff10
+//
+// The first line is the header, with the version number after "v". Every
+// further line is one call on the generator, in call order: `u32` and `u64`
+// for next_u32 and next_u64 with the value as 8 or 16 lowercase hex digits,
+// `bytes` for fill_bytes and try_fill_bytes with the output as two hex digits
+// per byte (nothing after the space for an empty request). Blank lines and
+// lines starting with '#' are ignored.
+
+pub const LOG_VERSION: u32 = 1;
+
+const HEADER_PREFIX: &str = "mdpc-gf-4 rng log v";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Draw {
+    U32(u32),
+    U64(u64),
+    Bytes(Vec<u8>),
+}
+
+#[derive(Debug)]
+pub enum RngLogError {
+    Io(io::Error),
+    MissingHeader,
+    UnsupportedVersion(String),
+    Malformed { line: usize, content: String },
+}
+
+impl fmt::Display for RngLogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RngLogError::Io(e) => write!(f, "i/o error: {}", e),
+            RngLogError::MissingHeader => write!(f, "missing '{}{}' header", HEADER_PREFIX, LOG_VERSION),
+            RngLogError::UnsupportedVersion(version) => write!(f, "unsupported rng log version '{}'", version),
+            RngLogError::Malformed { line, content } => write!(f, "malformed draw on line {}: '{}'", line, content),
+        }
+    }
+}
+
+impl std::error::Error for RngLogError {}
+
+impl From<io::Error> for RngLogError {
+    fn from(e: io::Error) -> Self {
+        RngLogError::Io(e)
+    }
+}
+
+fn header_line() -> String {
+    format!("{}{}\n", HEADER_PREFIX, LOG_VERSION)
+}
+
+fn draw_line(draw: &Draw) -> String {
+    match draw {
+        Draw::U32(value) => format!("u32 {:08x}\n", value),
+        Draw::U64(value) => format!("u64 {:016x}\n", value),
+        Draw::Bytes(bytes) => format!("bytes {}\n", to_hex(bytes)),
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RngLog {
+    draws: Vec<Draw>,
+}
+
+impl RngLog {
+    pub fn new() -> RngLog {
+        RngLog { draws: Vec::new() }
+    }
+
+    pub fn draws(&self) -> &[Draw] {
+        &self.draws
+    }
+
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(header_line().as_bytes())?;
+        for draw in self.draws.iter() {
+            writer.write_all(draw_line(draw).as_bytes())?;
+        }
+        Ok(())
+    }
+
+    pub fn read_from<R: BufRead>(reader: R) -> Result<RngLog, RngLogError> {
+        let mut lines = reader.lines();
+        let header = lines.next().ok_or(RngLogError::MissingHeader)??;
+        let version = header.strip_prefix(HEADER_PREFIX).ok_or(RngLogError::MissingHeader)?;
+        if version != LOG_VERSION.to_string() {
+            return Err(RngLogError::UnsupportedVersion(version.to_string()));
+        }
+        let mut draws = Vec::new();
+        for (index, line) in lines.enumerate() {
+            let line = line?;
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let malformed = || RngLogError::Malformed { line: index + 2, content: line.clone() };
+            let (kind, value) = line.split_once(' ').ok_or_else(malformed)?;
+            let draw = match kind {
+                "u32" if value.len() == 8 => from_hex(value).map(|b| Draw::U32(u32::from_be_bytes(b.try_into().unwrap()))),
+                "u64" if value.len() == 16 => from_hex(value).map(|b| Draw::U64(u64::from_be_bytes(b.try_into().unwrap()))),
+                "bytes" => from_hex(value).map(Draw::Bytes),
+                _ => None,
+            };
+            draws.push(draw.ok_or_else(malformed)?);
+        }
+        Ok(RngLog { draws })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_to(&mut writer)?;
+        writer.flush()
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<RngLog, RngLogError> {
+        RngLog::read_from(BufReader::new(File::open(path)?))
+    }
+}
+
+// Passes every draw of `inner` through and appends it to `sink` as one log
+// line, written with a single write_all, so nothing accumulates in memory.
+// The sink is flushed on drop, which also runs when a trial panics. With an
+// unbuffered File, as from `create`, every line reaches the operating system
+// as soon as it is drawn and survives an abort; a BufWriter is faster but
+// loses its unflushed tail if the process aborts.
+//
+// Write errors cannot be returned from RngCore methods. The first one stops
+// recording and is kept; `error` and `finish` report it.
+pub struct RecordingRng<R: RngCore, W: Write> {
+    inner: R,
+    sink: Option<W>,
+    draws: usize,
+    error: Option<io::Error>,
+}
+
+impl<R: RngCore, W: Write> RecordingRng<R, W> {
+    // Writes the log header to the sink right away.
+    pub fn new(inner: R, mut sink: W) -> io::Result<RecordingRng<R, W>> {
+        sink.write_all(header_line().as_bytes())?;
+        Ok(RecordingRng { inner, sink: Some(sink), draws: 0, error: None })
+    }
+
+    // Number of draws recorded so far.
+    pub fn draws(&self) -> usize {
+        self.draws
+    }
+
+    pub fn error(&self) -> Option<&io::Error> {
+        self.error.as_ref()
+    }
+
+    // Flushes and returns the sink, or the first write error met while recording.
+    pub fn finish(mut self) -> io::Result<W> {
+        let mut sink = self.sink.take().expect("sink is only taken by finish");
+        if let Some(e) = self.error.take() {
+            return Err(e);
+        }
+        sink.flush()?;
+        Ok(sink)
+    }
+
+    fn record(&mut self, draw: Draw) {
+        if self.error.is_some() {
+            return;
+        }
+        if let Some(sink) = self.sink.as_mut() {
+            match sink.write_all(draw_line(&draw).as_bytes()) {
+                Ok(()) => self.draws += 1,
+                Err(e) => self.error = Some(e),
+            }
+        }
+    }
+}
+
+impl<R: RngCore> RecordingRng<R, File> {
+    // Records into a new file at `path`, truncating an existing one.
+    pub fn create<P: AsRef<Path>>(inner: R, path: P) -> io::Result<RecordingRng<R, File>> {
+        RecordingRng::new(inner, File::create(path)?)
+    }
+}
+
+impl<R: RngCore, W: Write> Drop for RecordingRng<R, W> {
+    fn drop(&mut self) {
+        if let Some(sink) = self.sink.as_mut() {
+            let _ = sink.flush();
+        }
+    }
+}
+
+impl<R: RngCore, W: Write> RngCore for RecordingRng<R, W> {
+    fn next_u32(&mut self) -> u32 {
+        let value = self.inner.next_u32();
+        self.record(Draw::U32(value));
+        value
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let value = self.inner.next_u64();
+        self.record(Draw::U64(value));
+        value
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.inner.fill_bytes(dest);
+        self.record(Draw::Bytes(dest.to_vec()));
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.inner.try_fill_bytes(dest)?;
+        self.record(Draw::Bytes(dest.to_vec()));
+        Ok(())
+    }
+}
+
+impl<R: RngCore + CryptoRng, W: Write> CryptoRng for RecordingRng<R, W> {}
+
+// Feeds a recorded log back. A request that does not match the next recorded
+// draw (other kind, other length, or past the end) means the replayed code
+// diverged from the recorded run; next_u32, next_u64 and fill_bytes panic,
+// try_fill_bytes returns an error.
+pub struct ReplayRng {
+    draws: std::vec::IntoIter<Draw>,
+    position: usize,
+}
+
+impl ReplayRng {
+    pub fn new(log: RngLog) -> ReplayRng {
+        ReplayRng { draws: log.draws.into_iter(), position: 0 }
+    }
+
+    // Draws not yet replayed.
+    pub fn remaining(&self) -> usize {
+        self.draws.len()
+    }
+
+    fn next_draw(&mut self, expected: &str) -> Result<Draw, String> {
+        self.position += 1;
+        self.draws
+            .next()
+            .ok_or_else(|| format!("replay diverged at draw {}: requested {}, log exhausted", self.position, expected))
+    }
+
+    fn replay_bytes(&mut self, dest: &mut [u8]) -> Result<(), String> {
+        match self.next_draw("bytes")? {
+            Draw::Bytes(bytes) if bytes.len() == dest.len() => {
+                dest.copy_from_slice(&bytes);
+                Ok(())
+            }
+            other => Err(format!("replay diverged at draw {}: requested {} bytes, log has {:?}", self.position, dest.len(), other)),
+        }
+    }
+}
+
+impl RngCore for ReplayRng {
+    fn next_u32(&mut self) -> u32 {
+        match self.next_draw("u32") {
+            Ok(Draw::U32(value)) => value,
+            Ok(other) => panic!("replay diverged at draw {}: requested u32, log has {:?}", self.position, other),
+            Err(message) => panic!("{}", message),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self.next_draw("u64") {
+            Ok(Draw::U64(value)) => value,
+            Ok(other) => panic!("replay diverged at draw {}: requested u64, log has {:?}", self.position, other),
+            Err(message) => panic!("{}", message),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if let Err(message) = self.replay_bytes(dest) {
+            panic!("{}", message);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.replay_bytes(dest).map_err(rand::Error::new)
+    }
+}
+
+// Replays output that came from a CryptoRng; only meant for debugging.
+impl CryptoRng for ReplayRng {}
+
+#[cfg(test)]
+mod recording_tests {
+    use crate::galois_fields::gf4_number::GF4;
+    use crate::polynomials::polynomial::Polynomial;
+    use crate::random::context::Context;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+    use super::*;
+
+    // Key generation and error sampling of a toy trial.
+    fn trial<R: RngCore + CryptoRng>(ctx: &mut Context<R>) -> (Polynomial<GF4>, Vec<GF4>, Vec<GF4>, [u8; 7]) {
+        let key = ctx.random_invertible_polynomial(11).unwrap();
+        let error = ctx.random_error_vector(22, 3).unwrap();
+        let secret_error = ctx.constant_time_error_vector(22, 3).unwrap();
+        let mut bytes = [0u8; 7];
+        ctx.rng_mut().fill_bytes(&mut bytes);
+        (key, error, secret_error, bytes)
+    }
+
+    fn seeded() -> ChaCha20Rng {
+        ChaCha20Rng::from_seed([0x50; 32])
+    }
+
+    fn temp_log_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("mdpc-gf-4-{}-{}.txt", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let mut recording = Context::from_rng(RecordingRng::new(seeded(), Vec::new()).unwrap());
+        let recorded = trial(&mut recording);
+        let recorder = recording.into_rng();
+        let draws = recorder.draws();
+        let log = RngLog::read_from(&recorder.finish().unwrap()[..]).unwrap();
+        assert_eq!(log.draws().len(), draws);
+        assert!(log.draws().iter().any(|d| matches!(d, Draw::U32(_))));
+        assert!(log.draws().iter().any(|d| matches!(d, Draw::Bytes(_))));
+
+        let mut replay = Context::from_rng(ReplayRng::new(log));
+        assert_eq!(trial(&mut replay), recorded);
+        assert_eq!(replay.rng_mut().remaining(), 0);
+        // replay gives the same results as the unrecorded generator
+        assert_eq!(trial(&mut Context::from_rng(seeded())), recorded);
+    }
+
+    #[test]
+    fn test_log_format_roundtrip() {
+        let mut recording = Context::from_rng(RecordingRng::new(seeded(), Vec::new()).unwrap());
+        trial(&mut recording);
+        let mut empty = [0u8; 0];
+        recording.rng_mut().fill_bytes(&mut empty);
+        recording.rng_mut().next_u64();
+        let text = String::from_utf8(recording.into_rng().finish().unwrap()).unwrap();
+        assert!(text.starts_with("mdpc-gf-4 rng log v1\n"));
+        assert!(text.contains("\nbytes \n"));
+
+        // RngLog writes exactly what RecordingRng streamed
+        let log = RngLog::read_from(text.as_bytes()).unwrap();
+        let mut rewritten = Vec::new();
+        log.write_to(&mut rewritten).unwrap();
+        assert_eq!(String::from_utf8(rewritten).unwrap(), text);
+
+        let path = temp_log_path("rng-log");
+        log.save(&path).unwrap();
+        let loaded = RngLog::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, log);
+    }
+
+    #[test]
+    fn test_recording_streams_to_file() {
+        let path = temp_log_path("rng-stream");
+        let mut rng = RecordingRng::create(seeded(), &path).unwrap();
+        let first = rng.next_u32();
+        // the draw is in the file before the recorder is finished or dropped
+        let log = RngLog::load(&path).unwrap();
+        assert_eq!(log.draws(), &[Draw::U32(first)]);
+        rng.next_u64();
+        assert_eq!(RngLog::load(&path).unwrap().draws().len(), 2);
+        drop(rng);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_recording_flushes_on_drop() {
+        let path = temp_log_path("rng-drop");
+        let file = std::fs::File::create(&path).unwrap();
+        let mut rng = RecordingRng::new(seeded(), std::io::BufWriter::new(file)).unwrap();
+        let value = rng.next_u64();
+        drop(rng);
+        let log = RngLog::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(log.draws(), &[Draw::U64(value)]);
+    }
+
+    #[test]
+    fn test_recording_survives_panic() {
+        let path = temp_log_path("rng-panic");
+        let thread_path = path.clone();
+        let result = std::thread::spawn(move || {
+            let file = std::fs::File::create(&thread_path).unwrap();
+            let rng = RecordingRng::new(seeded(), std::io::BufWriter::new(file)).unwrap();
+            let mut ctx = Context::from_rng(rng);
+            trial(&mut ctx);
+            panic!("trial failed");
+        })
+        .join();
+        assert!(result.is_err());
+
+        let log = RngLog::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let mut replay = Context::from_rng(ReplayRng::new(log));
+        assert_eq!(trial(&mut replay), trial(&mut Context::from_rng(seeded())));
+        assert_eq!(replay.rng_mut().remaining(), 0);
+    }
+
+    // Accepts `limit` bytes, then fails every write.
+    struct FailingSink {
+        limit: usize,
+    }
+
+    impl Write for FailingSink {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if buf.len() > self.limit {
+                return Err(io::Error::other("sink full"));
+            }
+            self.limit -= buf.len();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_recording_write_error() {
+        assert!(RecordingRng::new(seeded(), FailingSink { limit: 3 }).is_err());
+        let mut rng = RecordingRng::new(seeded(), FailingSink { limit: header_line().len() + 13 }).unwrap();
+        rng.next_u32();
+        assert!(rng.error().is_none());
+        rng.next_u32();
+        assert!(rng.error().is_some());
+        rng.next_u32();
+        assert_eq!(rng.draws(), 1);
+        assert!(rng.finish().is_err());
+    }
+
+    #[test]
+    fn test_log_parsing() {
+        let text = "mdpc-gf-4 rng log v1\n# comment\nu32 0a1b2c3d\n\nu64 00000000deadbeef\nbytes 00ff10\n";
+        assert_eq!(
+            RngLog::read_from(text.as_bytes()).unwrap().draws(),
+            &[Draw::U32(0x0a1b2c3d), Draw::U64(0xdeadbeef), Draw::Bytes(vec![0x00, 0xff, 0x10])]
+        );
+        assert!(matches!(RngLog::read_from("".as_bytes()), Err(RngLogError::MissingHeader)));
+        assert!(matches!(RngLog::read_from("u32 00000000\n".as_bytes()), Err(RngLogError::MissingHeader)));
+        assert!(matches!(RngLog::read_from("mdpc-gf-4 rng log v2\n".as_bytes()), Err(RngLogError::UnsupportedVersion(v)) if v == "2"));
+        let bad = [
+            "u32 123", "u64 0123456789abcdeg", "bytes 0", "bits 00", "u32",
+            // only lowercase hex digits, and no sign
+            "u32 0A1B2C3D", "u32 +1234567", "u64 +123456789abcdef", "u64 00000000DEADBEEF", "bytes FF", "bytes +f",
+        ];
+        for bad in bad {
+            let text = format!("mdpc-gf-4 rng log v1\nu32 00000000\n{}\n", bad);
+            assert!(matches!(RngLog::read_from(text.as_bytes()), Err(RngLogError::Malformed { line: 3, .. })), "{}", bad);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "replay diverged at draw 2")]
+    fn test_replay_divergence() {
+        let text = "mdpc-gf-4 rng log v1\nu32 00000001\nu32 00000002\n";
+        let mut rng = ReplayRng::new(RngLog::read_from(text.as_bytes()).unwrap());
+        assert_eq!(rng.next_u32(), 1);
+        rng.next_u64();
+    }
+
+    #[test]
+    fn test_replay_try_fill_bytes_error() {
+        let text = "mdpc-gf-4 rng log v1\nbytes 0102\n";
+        let mut rng = ReplayRng::new(RngLog::read_from(text.as_bytes()).unwrap());
+        let mut three = [0u8; 3];
+        assert!(rng.try_fill_bytes(&mut three).is_err());
+        let mut two = [0u8; 2];
+        assert!(rng.try_fill_bytes(&mut two).is_err());
+    }
+}
@@ -0,0 +1,225 @@
+// Test-only goodness-of-fit helpers for checking the samplers, and the
+// statistical tests themselves. Every test runs from a fixed seed, so a pass
+// or fail is reproducible in CI; SIGNIFICANCE_LEVEL is the probability that a
+// test would reject a correct sampler for a random seed.
+
+pub const SIGNIFICANCE_LEVEL: f64 = 0.001;
+
+// Upper SIGNIFICANCE_LEVEL quantile of the standard normal distribution.
+const Z_QUANTILE: f64 = 3.090232;
+
+// Pearson's statistic of observed counts against expected counts.
+pub fn chi_square_statistic(observed: &[usize], expected: &[f64]) -> f64 {
+    observed
+        .iter()
+        .zip(expected.iter())
+        .map(|(&o, &e)| (o as f64 - e).powi(2) / e)
+        .sum()
+}
+
+// Pearson's statistic against equal expected counts; len - 1 degrees of freedom.
+pub fn chi_square_uniform(observed: &[usize]) -> f64 {
+    let total: usize = observed.iter().sum();
+    let expected = vec![total as f64 / observed.len() as f64; observed.len()];
+    chi_square_statistic(observed, &expected)
+}
+
+// Pearson's test of independence on a contingency table. Returns the
+// statistic and its (rows - 1)(cols - 1) degrees of freedom.
+pub fn chi_square_independence(table: &[Vec<usize>]) -> (f64, usize) {
+    let rows = table.len();
+    let cols = table[0].len();
+    let row_sums: Vec<usize> = table.iter().map(|row| row.iter().sum()).collect();
+    let col_sums: Vec<usize> = (0..cols).map(|j| table.iter().map(|row| row[j]).sum()).collect();
+    let total: usize = row_sums.iter().sum();
+    let mut statistic = 0.0;
+    for i in 0..rows {
+        for j in 0..cols {
+            let expected = row_sums[i] as f64 * col_sums[j] as f64 / total as f64;
+            statistic += (table[i][j] as f64 - expected).powi(2) / expected;
+        }
+    }
+    (statistic, (rows - 1) * (cols - 1))
+}
+
+// Critical value of the chi-square distribution at SIGNIFICANCE_LEVEL.
+// Exact for one and two degrees of freedom, otherwise the Wilson-Hilferty
+// approximation, which errs on the high side by under 2% from three degrees
+// of freedom on.
+pub fn chi_square_critical_value(degrees_of_freedom: usize) -> f64 {
+    match degrees_of_freedom {
+        0 => 0.0,
+        1 => 10.828,
+        2 => -2.0 * SIGNIFICANCE_LEVEL.ln(),
+        k => {
+            let k = k as f64;
+            let c = 2.0 / (9.0 * k);
+            k * (1.0 - c + Z_QUANTILE * c.sqrt()).powi(3)
+        }
+    }
+}
+
+#[cfg(test)]
+mod statistics_tests {
+    use rand::{RngCore, CryptoRng, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
+    use crate::galois_fields::GaloisField;
+    use crate::galois_fields::gf4_number::GF4;
+    use crate::random::context::Context;
+    use super::*;
+
+    fn assert_uniform(observed: &[usize], what: &str) {
+        let statistic = chi_square_uniform(observed);
+        let critical = chi_square_critical_value(observed.len() - 1);
+        assert!(statistic < critical, "{}: chi-square {} >= {} for {:?}", what, statistic, critical, observed);
+    }
+
+    fn assert_independent(table: &[Vec<usize>], what: &str) {
+        let (statistic, degrees_of_freedom) = chi_square_independence(table);
+        let critical = chi_square_critical_value(degrees_of_freedom);
+        assert!(statistic < critical, "{}: chi-square {} >= {} for {:?}", what, statistic, critical, table);
+    }
+
+    #[test]
+    fn test_critical_values() {
+        // Published 0.001 quantiles.
+        for (degrees_of_freedom, table_value) in [(1, 10.828), (2, 13.816), (3, 16.266), (9, 27.877), (19, 43.820), (63, 103.442)] {
+            let value = chi_square_critical_value(degrees_of_freedom);
+            assert!(value >= table_value - 0.01 && value < table_value * 1.02, "{} {}", degrees_of_freedom, value);
+        }
+    }
+
+    #[test]
+    fn test_statistics_detect_bias() {
+        assert!(chi_square_uniform(&[250, 250, 250, 250]).abs() < 1e-12);
+        assert!(chi_square_uniform(&[300, 250, 250, 200]) > chi_square_critical_value(3));
+        let (statistic, degrees_of_freedom) = chi_square_independence(&[vec![100, 0], vec![0, 100]]);
+        assert_eq!(degrees_of_freedom, 1);
+        assert!(statistic > chi_square_critical_value(1));
+        assert!(chi_square_independence(&[vec![50, 50], vec![100, 100]]).0.abs() < 1e-12);
+    }
+
+    // generate_random over a fixed seed, as counts indexed by GF4::to_number.
+    fn symbol_counts<R: RngCore + CryptoRng>(ctx: &mut Context<R>, samples: usize) -> Vec<usize> {
+        let mut counts = vec![0; 4];
+        for x in ctx.random_vector::<GF4>(samples) {
+            counts[x.to_number() as usize] += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn test_generate_random_frequencies() {
+        let mut rng = ChaCha20Rng::from_seed([0x48; 32]);
+        let mut counts = vec![0; 4];
+        for _ in 0..10000 {
+            counts[GF4::generate_random(&mut rng).to_number() as usize] += 1;
+        }
+        assert_uniform(&counts, "GF4::generate_random");
+    }
+
+    #[test]
+    fn test_random_vector_frequencies() {
+        assert_uniform(&symbol_counts(&mut Context::from_seed([0x49; 32]), 10000), "ChaCha20 random_vector");
+        assert_uniform(&symbol_counts(&mut Context::from_shake256_seed(b"statistics"), 10000), "SHAKE256 random_vector");
+        let kat_seed: [u8; 48] = core::array::from_fn(|i| i as u8);
+        assert_uniform(&symbol_counts(&mut Context::from_kat_seed(&kat_seed), 10000), "CTR_DRBG random_vector");
+    }
+
+    #[test]
+    fn test_random_vector_pairwise_independence() {
+        // Neighbouring symbols, and symbols 7 apart, must be independent.
+        let mut ctx = Context::from_seed([0x4a; 32]);
+        let v: Vec<GF4> = ctx.random_vector(20000);
+        for distance in [1, 7] {
+            let mut table = vec![vec![0; 4]; 4];
+            for (a, b) in v.iter().zip(v.iter().skip(distance)) {
+                table[a.to_number() as usize][b.to_number() as usize] += 1;
+            }
+            assert_independent(&table, "random_vector pairs");
+            // and each of the 16 pairs is equally likely
+            assert_uniform(&table.concat(), "random_vector pair frequencies");
+        }
+    }
+
+    // Counts over many error vectors, each sampled as its (position, value) pairs.
+    struct ErrorStatistics {
+        positions: Vec<usize>,
+        values: Vec<usize>,
+        position_pairs: Vec<usize>,
+        position_value: Vec<Vec<usize>>,
+    }
+
+    fn collect_error_statistics(length: usize, trials: usize, mut sample: impl FnMut() -> Vec<(usize, GF4)>) -> ErrorStatistics {
+        let mut stats = ErrorStatistics {
+            positions: vec![0; length],
+            values: vec![0; 3],
+            position_pairs: vec![0; length * length],
+            position_value: vec![vec![0; 3]; length],
+        };
+        for _ in 0..trials {
+            let error = sample();
+            for (position, value) in error.iter() {
+                let value = value.to_number() as usize;
+                assert!(value != 0);
+                stats.positions[*position] += 1;
+                stats.values[value - 1] += 1;
+                stats.position_value[*position][value - 1] += 1;
+                for (other, _) in error.iter() {
+                    if position < other {
+                        stats.position_pairs[position * length + other] += 1;
+                    }
+                }
+            }
+        }
+        stats
+    }
+
+    fn assert_error_statistics(stats: &ErrorStatistics, length: usize, what: &str) {
+        assert_uniform(&stats.positions, what);
+        assert_uniform(&stats.values, what);
+        // every pair of distinct positions is equally likely to be covered together
+        let pairs: Vec<usize> = (0..length)
+            .flat_map(|i| (i + 1..length).map(move |j| (i, j)))
+            .map(|(i, j)| stats.position_pairs[i * length + j])
+            .collect();
+        assert_uniform(&pairs, what);
+        // the value at a position does not depend on the position
+        assert_independent(&stats.position_value, what);
+    }
+
+    fn nonzero_entries(v: &[GF4]) -> Vec<(usize, GF4)> {
+        v.iter().cloned().enumerate().filter(|(_, x)| !x.is_zero()).collect()
+    }
+
+    const LENGTH: usize = 12;
+    const WEIGHT: usize = 4;
+    const TRIALS: usize = 6000;
+
+    #[test]
+    fn test_random_error_vector_distribution() {
+        let mut ctx = Context::from_seed([0x4b; 32]);
+        let stats = collect_error_statistics(LENGTH, TRIALS, || {
+            nonzero_entries(&ctx.random_error_vector(LENGTH, WEIGHT).unwrap())
+        });
+        assert_error_statistics(&stats, LENGTH, "random_error_vector");
+    }
+
+    #[test]
+    fn test_constant_time_error_vector_distribution() {
+        let mut ctx = Context::from_seed([0x4c; 32]);
+        let stats = collect_error_statistics(LENGTH, TRIALS, || {
+            nonzero_entries(&ctx.constant_time_error_vector(LENGTH, WEIGHT).unwrap())
+        });
+        assert_error_statistics(&stats, LENGTH, "constant_time_error_vector");
+    }
+
+    #[test]
+    fn test_random_sparse_polynomial_distribution() {
+        let mut ctx = Context::from_seed([0x4d; 32]);
+        let stats = collect_error_statistics(LENGTH, TRIALS, || {
+            ctx.random_sparse_polynomial::<GF4>(LENGTH, WEIGHT).unwrap().terms().to_vec()
+        });
+        assert_error_statistics(&stats, LENGTH, "random_sparse_polynomial");
+    }
+}